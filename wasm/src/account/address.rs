@@ -14,7 +14,10 @@
 // You should have received a copy of the GNU General Public License
 // along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::account::{PrivateKey, Signature, ViewKey};
+use crate::{
+    account::{PrivateKey, Signature, ViewKey},
+    AleoError,
+};
 
 use crate::types::native::AddressNative;
 use core::{convert::TryFrom, fmt, ops::Deref, str::FromStr};
@@ -47,8 +50,8 @@ impl Address {
     ///
     /// @param {string} address String representation of an addressm
     /// @returns {Address} Address
-    pub fn from_string(address: &str) -> Self {
-        Self::from_str(address).unwrap()
+    pub fn from_string(address: &str) -> Result<Address, AleoError> {
+        Self::from_str(address).map_err(|_| AleoError::parse("Invalid address"))
     }
 
     /// Get a string representation of an Aleo address object
@@ -67,6 +70,16 @@ impl Address {
     pub fn verify(&self, message: &[u8], signature: &Signature) -> bool {
         signature.verify(self, message)
     }
+
+    /// Verify a signature for an Aleo plaintext value signed by the address
+    ///
+    /// @param {string} message String representation of the plaintext value signed by the address
+    /// @param {Signature} signature Signature of the value
+    /// @returns {boolean} Boolean representing whether or not the signature is valid
+    #[wasm_bindgen(js_name = verifyValue)]
+    pub fn verify_value(&self, message: &str, signature: &Signature) -> bool {
+        signature.verify_value(self, message)
+    }
 }
 
 impl FromStr for Address {
@@ -118,4 +131,14 @@ mod tests {
             assert_eq!(expected, Address::from_view_key(&view_key));
         }
     }
+
+    #[wasm_bindgen_test]
+    pub fn test_from_string() {
+        let address = PrivateKey::new().to_address();
+        assert_eq!(address, Address::from_string(&address.to_string()).unwrap());
+
+        // Check invalid addresses return a parse error instead of panicking.
+        let error = Address::from_string("aleo1invalid").unwrap_err();
+        assert_eq!(error.code(), crate::ErrorCode::Parse);
+    }
 }
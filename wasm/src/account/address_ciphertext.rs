@@ -0,0 +1,123 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{account::SymmetricCiphertext, AleoError};
+
+use crate::types::native::GroupNative;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use wasm_bindgen::prelude::*;
+
+/// Version of the address ciphertext format
+const ADDRESS_CIPHERTEXT_VERSION: u8 = 1;
+
+/// Ciphertext of a plaintext encrypted to an Aleo address. The sender publishes an ephemeral
+/// nonce `G * r` alongside a symmetric ciphertext keyed by the shared secret `(address * r).x`,
+/// which the recipient recovers as `(nonce * view_key).x`, exactly as record data is encrypted
+#[wasm_bindgen]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AddressCiphertext {
+    nonce: GroupNative,
+    ciphertext: SymmetricCiphertext,
+}
+
+/// Versioned envelope used to serialize address ciphertexts
+#[derive(Deserialize, Serialize)]
+struct AddressCiphertextEnvelope {
+    version: u8,
+    nonce: String,
+    ciphertext: serde_json::Value,
+}
+
+#[wasm_bindgen]
+impl AddressCiphertext {
+    /// Returns the domain the plaintext was encrypted under
+    ///
+    /// @returns {string} Domain
+    pub fn domain(&self) -> String {
+        self.ciphertext.domain()
+    }
+
+    /// Returns the JSON string representation of the ciphertext
+    ///
+    /// @returns {string} Ciphertext string
+    #[allow(clippy::inherent_to_string)]
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_string(&self) -> String {
+        serde_json::to_string(&AddressCiphertextEnvelope {
+            version: ADDRESS_CIPHERTEXT_VERSION,
+            nonce: self.nonce.to_string(),
+            ciphertext: serde_json::from_str(&self.ciphertext.to_string()).unwrap(),
+        })
+        .unwrap()
+    }
+
+    /// Creates an AddressCiphertext from its JSON string representation
+    ///
+    /// @param {string} ciphertext Ciphertext string
+    /// @returns {AddressCiphertext} Address ciphertext
+    #[wasm_bindgen(js_name = fromString)]
+    pub fn from_string(ciphertext: &str) -> Result<AddressCiphertext, AleoError> {
+        let envelope: AddressCiphertextEnvelope =
+            serde_json::from_str(ciphertext).map_err(|_| AleoError::parse("Invalid ciphertext"))?;
+        if envelope.version != ADDRESS_CIPHERTEXT_VERSION {
+            return Err(AleoError::parse(format!("Unsupported ciphertext version {}", envelope.version)));
+        }
+        let nonce = GroupNative::from_str(&envelope.nonce).map_err(|_| AleoError::parse("Invalid ciphertext nonce"))?;
+        let ciphertext = SymmetricCiphertext::from_string(&envelope.ciphertext.to_string())?;
+        if ciphertext.is_password_protected() {
+            return Err(AleoError::parse("Invalid ciphertext"));
+        }
+        Ok(Self::new(nonce, ciphertext))
+    }
+}
+
+impl AddressCiphertext {
+    pub(crate) fn new(nonce: GroupNative, ciphertext: SymmetricCiphertext) -> Self {
+        Self { nonce, ciphertext }
+    }
+
+    /// Returns the ephemeral nonce the shared secret is derived from
+    pub fn nonce(&self) -> GroupNative {
+        self.nonce
+    }
+
+    /// Returns the symmetric ciphertext keyed by the shared secret
+    pub fn ciphertext(&self) -> &SymmetricCiphertext {
+        &self.ciphertext
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::account::{Encryptor, PrivateKey};
+
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    #[wasm_bindgen_test]
+    fn test_address_ciphertext_to_and_from_string() {
+        let address = PrivateKey::new().to_address();
+        let ciphertext = Encryptor::encrypt_plaintext_for_address("{ memo: 1field }", &address, "memo").unwrap();
+        let recovered = AddressCiphertext::from_string(&ciphertext.to_string()).unwrap();
+        assert_eq!(ciphertext, recovered);
+        assert_eq!(recovered.domain(), "memo");
+
+        assert!(AddressCiphertext::from_string("garbage").is_err());
+        let tampered = ciphertext.to_string().replace("\"version\":1,\"nonce\"", "\"version\":2,\"nonce\"");
+        assert!(AddressCiphertext::from_string(&tampered).is_err());
+    }
+}
@@ -0,0 +1,124 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    account::{Address, PrivateKey},
+    types::{Group, Scalar},
+    AleoError,
+};
+
+use crate::types::native::ComputeKeyNative;
+use core::{convert::TryFrom, ops::Deref};
+use wasm_bindgen::prelude::*;
+
+/// Compute key of an Aleo account. The compute key can be used to authorize transitions and
+/// derive the account address without revealing the signing secret of the private key, which
+/// makes it suitable for delegated proving
+#[wasm_bindgen]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ComputeKey(ComputeKeyNative);
+
+#[wasm_bindgen]
+impl ComputeKey {
+    /// Create a new compute key from a private key
+    ///
+    /// @param {PrivateKey} private_key Private key
+    /// @returns {ComputeKey} Compute key
+    #[wasm_bindgen(js_name = "fromPrivateKey")]
+    pub fn from_private_key(private_key: &PrivateKey) -> Result<ComputeKey, AleoError> {
+        Ok(Self(ComputeKeyNative::try_from(**private_key).map_err(AleoError::invalid_input)?))
+    }
+
+    /// Get the address corresponding to the compute key
+    ///
+    /// @returns {Address} Address
+    pub fn address(&self) -> Address {
+        Address::from(self.0.to_address())
+    }
+
+    /// Get the signature public key of the compute key
+    ///
+    /// @returns {Group} pk_sig
+    pub fn pk_sig(&self) -> Group {
+        Group::from(self.0.pk_sig())
+    }
+
+    /// Get the signature public randomizer of the compute key
+    ///
+    /// @returns {Group} pr_sig
+    pub fn pr_sig(&self) -> Group {
+        Group::from(self.0.pr_sig())
+    }
+
+    /// Get the PRF secret key of the compute key
+    ///
+    /// @returns {Scalar} sk_prf
+    pub fn sk_prf(&self) -> Scalar {
+        Scalar::from(self.0.sk_prf())
+    }
+}
+
+impl From<ComputeKeyNative> for ComputeKey {
+    fn from(compute_key: ComputeKeyNative) -> Self {
+        Self(compute_key)
+    }
+}
+
+impl From<ComputeKey> for ComputeKeyNative {
+    fn from(compute_key: ComputeKey) -> Self {
+        compute_key.0
+    }
+}
+
+impl Deref for ComputeKey {
+    type Target = ComputeKeyNative;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use wasm_bindgen_test::*;
+
+    const ITERATIONS: u64 = 1_000;
+
+    const ALEO_PRIVATE_KEY: &str = "APrivateKey1zkp3dQx4WASWYQVWKkq14v3RoQDfY2kbLssUj7iifi1VUQ6";
+    const ALEO_ADDRESS: &str = "aleo184vuwr5u7u0ha5f5k44067dd2uaqewxx6pe5ltha5pv99wvhfqxqv339h4";
+
+    #[wasm_bindgen_test]
+    pub fn test_sanity_check() {
+        let private_key = PrivateKey::from_string(ALEO_PRIVATE_KEY).unwrap();
+        let compute_key = ComputeKey::from_private_key(&private_key).unwrap();
+        assert_eq!(ALEO_ADDRESS, compute_key.address().to_string());
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_address_matches_view_key() {
+        for _ in 0..ITERATIONS {
+            // Sample a new private key.
+            let private_key = PrivateKey::new();
+            let compute_key = ComputeKey::from_private_key(&private_key).unwrap();
+
+            // Check the address derived from the compute key matches the view key derivation.
+            assert_eq!(compute_key.address(), private_key.to_view_key().to_address());
+            assert_eq!(compute_key.address(), private_key.to_address());
+        }
+    }
+}
@@ -14,93 +14,544 @@
 // You should have received a copy of the GNU General Public License
 // along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
 
+use crate::{
+    account::{Address, AddressCiphertext, PrivateKeyCiphertext, SymmetricCiphertext, ViewKey},
+    types::Field,
+    AleoError,
+};
+
 use crate::types::native::{
     CiphertextNative,
     CurrentNetwork,
+    Environment,
     FieldNative,
+    FromBytes,
     IdentifierNative,
     LiteralNative,
     Network,
     PlaintextNative,
+    PrimeField,
     PrivateKeyNative,
+    ScalarNative,
+    ToBytes,
     Uniform,
+    ViewKeyNative,
 };
 
 use once_cell::sync::OnceCell;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
 use std::str::FromStr;
+use wasm_bindgen::prelude::*;
+
+/// Default base 2 logarithm of the scrypt CPU/memory cost (32 MiB of memory with `r = 8`)
+const SCRYPT_LOG_N: u8 = 15;
+/// Default scrypt block size
+const SCRYPT_R: u32 = 8;
+/// Default scrypt parallelization parameter
+const SCRYPT_P: u32 = 1;
+/// Maximum base 2 logarithm of the scrypt cost accepted when decrypting (1 GiB of memory with `r = 8`)
+const SCRYPT_MAX_LOG_N: u8 = 20;
+/// Maximum scrypt block size accepted when decrypting
+const SCRYPT_MAX_R: u32 = 32;
+/// Maximum scrypt parallelization parameter accepted when decrypting
+const SCRYPT_MAX_P: u32 = 16;
+/// Maximum memory in bytes the scrypt parameters may require (`128 * r * 2^log_n`)
+const SCRYPT_MAX_MEMORY: u64 = 1 << 28;
+/// Maximum work the scrypt parameters may require (`p * r * 2^log_n`), 16 times the default cost
+const SCRYPT_MAX_WORK: u64 = 1 << 22;
+/// Length in bytes of the salt sampled for the key derivation function
+const SALT_LENGTH: usize = 16;
+/// Number of bytes packed into each field element when encrypting byte blobs
+const BYTES_PER_FIELD: usize = 31;
+/// Maximum number of bytes in an encrypted byte blob. Blobs are packed into a single plaintext array
+/// whose first element holds the length, leaving the remaining elements for 31 byte chunks
+const MAX_BYTES_LENGTH: usize = (CurrentNetwork::MAX_ARRAY_ELEMENTS - 1) * BYTES_PER_FIELD;
+
+/// Content kind of ciphertexts holding an Aleo plaintext value
+const PLAINTEXT_CONTENT: &str = "plaintext";
+/// Content kind of ciphertexts holding a byte blob
+const BYTES_CONTENT: &str = "bytes";
+/// Content kind of ciphertexts holding a view key
+const VIEW_KEY_CONTENT: &str = "view_key";
+
+/// Parameters of the scrypt key derivation function used to stretch a secret into an encryption key
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct KdfParams {
+    /// Base 2 logarithm of the scrypt CPU/memory cost
+    pub log_n: u8,
+    /// Block size
+    pub r: u32,
+    /// Parallelization parameter
+    pub p: u32,
+    /// Hex encoded salt
+    pub salt: String,
+}
+
+impl KdfParams {
+    /// Sample new key derivation parameters with the default cost and a random salt
+    pub fn new() -> Self {
+        let salt: [u8; SALT_LENGTH] = StdRng::from_entropy().gen();
+        Self::with_salt(&salt)
+    }
+
+    /// Key derivation parameters with the default cost and a fixed salt
+    pub(crate) fn with_salt(salt: &[u8]) -> Self {
+        Self { log_n: SCRYPT_LOG_N, r: SCRYPT_R, p: SCRYPT_P, salt: hex::encode(salt) }
+    }
+}
+
+impl Default for KdfParams {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Key material used to encrypt or decrypt a symmetric ciphertext
+enum EncryptionKey<'a> {
+    Password(&'a str),
+    Field(FieldNative),
+}
 
 /// Tool for encrypting and decrypting Aleo key material into ciphertext
+#[wasm_bindgen]
 pub struct Encryptor;
 
+#[wasm_bindgen]
 impl Encryptor {
-    /// Encrypt a private key into ciphertext using a secret
+    /// Encrypt an Aleo plaintext value under a password and a caller chosen domain
+    ///
+    /// @param {string} plaintext String representation of an Aleo plaintext value
+    /// @param {string} secret Password to encrypt the plaintext with
+    /// @param {string} domain Domain separating this use of the password from others
+    /// @returns {SymmetricCiphertext} Ciphertext of the plaintext
+    #[wasm_bindgen(js_name = encryptPlaintext)]
+    pub fn encrypt_plaintext(plaintext: &str, secret: &str, domain: &str) -> Result<SymmetricCiphertext, AleoError> {
+        let plaintext = PlaintextNative::from_str(plaintext).map_err(AleoError::parse)?;
+        Self::encrypt_content(plaintext, PLAINTEXT_CONTENT, domain, EncryptionKey::Password(secret))
+    }
+
+    /// Encrypt an Aleo plaintext value under a field key and a caller chosen domain
+    ///
+    /// @param {string} plaintext String representation of an Aleo plaintext value
+    /// @param {Field} key Field key to encrypt the plaintext with
+    /// @param {string} domain Domain separating this use of the key from others
+    /// @returns {SymmetricCiphertext} Ciphertext of the plaintext
+    #[wasm_bindgen(js_name = encryptPlaintextWithKey)]
+    pub fn encrypt_plaintext_with_key(
+        plaintext: &str,
+        key: &Field,
+        domain: &str,
+    ) -> Result<SymmetricCiphertext, AleoError> {
+        let plaintext = PlaintextNative::from_str(plaintext).map_err(AleoError::parse)?;
+        Self::encrypt_content(plaintext, PLAINTEXT_CONTENT, domain, EncryptionKey::Field(key.clone().into()))
+    }
+
+    /// Decrypt an Aleo plaintext value encrypted under a password
+    ///
+    /// @param {SymmetricCiphertext} ciphertext Ciphertext of the plaintext
+    /// @param {string} secret Password the plaintext was encrypted with
+    /// @returns {string} String representation of the plaintext value
+    #[wasm_bindgen(js_name = decryptPlaintext)]
+    pub fn decrypt_plaintext(ciphertext: &SymmetricCiphertext, secret: &str) -> Result<String, AleoError> {
+        Ok(Self::decrypt_content(ciphertext, PLAINTEXT_CONTENT, EncryptionKey::Password(secret))?.to_string())
+    }
+
+    /// Decrypt an Aleo plaintext value encrypted under a field key
+    ///
+    /// @param {SymmetricCiphertext} ciphertext Ciphertext of the plaintext
+    /// @param {Field} key Field key the plaintext was encrypted with
+    /// @returns {string} String representation of the plaintext value
+    #[wasm_bindgen(js_name = decryptPlaintextWithKey)]
+    pub fn decrypt_plaintext_with_key(ciphertext: &SymmetricCiphertext, key: &Field) -> Result<String, AleoError> {
+        Ok(Self::decrypt_content(ciphertext, PLAINTEXT_CONTENT, EncryptionKey::Field(key.clone().into()))?.to_string())
+    }
+
+    /// Encrypt a byte blob of at most 961 bytes under a password and a caller chosen domain
+    ///
+    /// @param {Uint8Array} bytes Bytes to encrypt
+    /// @param {string} secret Password to encrypt the bytes with
+    /// @param {string} domain Domain separating this use of the password from others
+    /// @returns {SymmetricCiphertext} Ciphertext of the bytes
+    #[wasm_bindgen(js_name = encryptBytes)]
+    pub fn encrypt_bytes(bytes: &[u8], secret: &str, domain: &str) -> Result<SymmetricCiphertext, AleoError> {
+        Self::encrypt_content(Self::bytes_to_plaintext(bytes)?, BYTES_CONTENT, domain, EncryptionKey::Password(secret))
+    }
+
+    /// Encrypt a byte blob of at most 961 bytes under a field key and a caller chosen domain
+    ///
+    /// @param {Uint8Array} bytes Bytes to encrypt
+    /// @param {Field} key Field key to encrypt the bytes with
+    /// @param {string} domain Domain separating this use of the key from others
+    /// @returns {SymmetricCiphertext} Ciphertext of the bytes
+    #[wasm_bindgen(js_name = encryptBytesWithKey)]
+    pub fn encrypt_bytes_with_key(bytes: &[u8], key: &Field, domain: &str) -> Result<SymmetricCiphertext, AleoError> {
+        let key = EncryptionKey::Field(key.clone().into());
+        Self::encrypt_content(Self::bytes_to_plaintext(bytes)?, BYTES_CONTENT, domain, key)
+    }
+
+    /// Decrypt a byte blob encrypted under a password
+    ///
+    /// @param {SymmetricCiphertext} ciphertext Ciphertext of the bytes
+    /// @param {string} secret Password the bytes were encrypted with
+    /// @returns {Uint8Array} Decrypted bytes
+    #[wasm_bindgen(js_name = decryptBytes)]
+    pub fn decrypt_bytes(ciphertext: &SymmetricCiphertext, secret: &str) -> Result<Vec<u8>, AleoError> {
+        Self::plaintext_to_bytes(&Self::decrypt_content(ciphertext, BYTES_CONTENT, EncryptionKey::Password(secret))?)
+    }
+
+    /// Decrypt a byte blob encrypted under a field key
+    ///
+    /// @param {SymmetricCiphertext} ciphertext Ciphertext of the bytes
+    /// @param {Field} key Field key the bytes were encrypted with
+    /// @returns {Uint8Array} Decrypted bytes
+    #[wasm_bindgen(js_name = decryptBytesWithKey)]
+    pub fn decrypt_bytes_with_key(ciphertext: &SymmetricCiphertext, key: &Field) -> Result<Vec<u8>, AleoError> {
+        let key = EncryptionKey::Field(key.clone().into());
+        Self::plaintext_to_bytes(&Self::decrypt_content(ciphertext, BYTES_CONTENT, key)?)
+    }
+
+    /// Encrypt a view key under a password and a caller chosen domain
+    ///
+    /// @param {ViewKey} view_key View key to encrypt
+    /// @param {string} secret Password to encrypt the view key with
+    /// @param {string} domain Domain separating this use of the password from others
+    /// @returns {SymmetricCiphertext} Ciphertext of the view key
+    #[wasm_bindgen(js_name = encryptViewKey)]
+    pub fn encrypt_view_key(view_key: &ViewKey, secret: &str, domain: &str) -> Result<SymmetricCiphertext, AleoError> {
+        let key = EncryptionKey::Password(secret);
+        Self::encrypt_content(Self::view_key_to_plaintext(view_key)?, VIEW_KEY_CONTENT, domain, key)
+    }
+
+    /// Encrypt a view key under a field key and a caller chosen domain
+    ///
+    /// @param {ViewKey} view_key View key to encrypt
+    /// @param {Field} key Field key to encrypt the view key with
+    /// @param {string} domain Domain separating this use of the key from others
+    /// @returns {SymmetricCiphertext} Ciphertext of the view key
+    #[wasm_bindgen(js_name = encryptViewKeyWithKey)]
+    pub fn encrypt_view_key_with_key(
+        view_key: &ViewKey,
+        key: &Field,
+        domain: &str,
+    ) -> Result<SymmetricCiphertext, AleoError> {
+        let key = EncryptionKey::Field(key.clone().into());
+        Self::encrypt_content(Self::view_key_to_plaintext(view_key)?, VIEW_KEY_CONTENT, domain, key)
+    }
+
+    /// Decrypt a view key encrypted under a password
+    ///
+    /// @param {SymmetricCiphertext} ciphertext Ciphertext of the view key
+    /// @param {string} secret Password the view key was encrypted with
+    /// @returns {ViewKey} View key
+    #[wasm_bindgen(js_name = decryptViewKey)]
+    pub fn decrypt_view_key(ciphertext: &SymmetricCiphertext, secret: &str) -> Result<ViewKey, AleoError> {
+        Self::plaintext_to_view_key(&Self::decrypt_content(
+            ciphertext,
+            VIEW_KEY_CONTENT,
+            EncryptionKey::Password(secret),
+        )?)
+    }
+
+    /// Decrypt a view key encrypted under a field key
+    ///
+    /// @param {SymmetricCiphertext} ciphertext Ciphertext of the view key
+    /// @param {Field} key Field key the view key was encrypted with
+    /// @returns {ViewKey} View key
+    #[wasm_bindgen(js_name = decryptViewKeyWithKey)]
+    pub fn decrypt_view_key_with_key(ciphertext: &SymmetricCiphertext, key: &Field) -> Result<ViewKey, AleoError> {
+        let key = EncryptionKey::Field(key.clone().into());
+        Self::plaintext_to_view_key(&Self::decrypt_content(ciphertext, VIEW_KEY_CONTENT, key)?)
+    }
+
+    /// Encrypt an Aleo plaintext value to an address. Only the holder of the view key of the address
+    /// can decrypt it, using the same Diffie-Hellman construction that encrypts record data
+    ///
+    /// @param {string} plaintext String representation of an Aleo plaintext value
+    /// @param {Address} address Address of the recipient
+    /// @param {string} domain Domain separating this use of the shared secret from others
+    /// @returns {AddressCiphertext} Ciphertext of the plaintext
+    #[wasm_bindgen(js_name = encryptPlaintextForAddress)]
+    pub fn encrypt_plaintext_for_address(
+        plaintext: &str,
+        address: &Address,
+        domain: &str,
+    ) -> Result<AddressCiphertext, AleoError> {
+        let plaintext = PlaintextNative::from_str(plaintext).map_err(AleoError::parse)?;
+
+        // Sample an ephemeral scalar and derive the nonce and the secret shared with the recipient
+        let randomizer: ScalarNative = Uniform::rand(&mut rand::thread_rng());
+        let nonce = CurrentNetwork::g_scalar_multiply(&randomizer);
+        let secret = (*address.to_group() * randomizer).to_x_coordinate();
+
+        let ciphertext = Self::encrypt_content(plaintext, PLAINTEXT_CONTENT, domain, EncryptionKey::Field(secret))?;
+        Ok(AddressCiphertext::new(nonce, ciphertext))
+    }
+
+    /// Decrypt an Aleo plaintext value encrypted to the address of a view key
+    ///
+    /// @param {AddressCiphertext} ciphertext Ciphertext of the plaintext
+    /// @param {ViewKey} view_key View key of the recipient
+    /// @returns {string} String representation of the plaintext value
+    #[wasm_bindgen(js_name = decryptPlaintextWithViewKey)]
+    pub fn decrypt_plaintext_with_view_key(
+        ciphertext: &AddressCiphertext,
+        view_key: &ViewKey,
+    ) -> Result<String, AleoError> {
+        let secret = (ciphertext.nonce() * ***view_key).to_x_coordinate();
+        let plaintext =
+            Self::decrypt_content(ciphertext.ciphertext(), PLAINTEXT_CONTENT, EncryptionKey::Field(secret))?;
+        Ok(plaintext.to_string())
+    }
+}
+
+impl Encryptor {
+    /// Encrypt a private key into ciphertext using a secret stretched by the key derivation function
     pub(crate) fn encrypt_private_key_with_secret(
         private_key: &PrivateKeyNative,
         secret: &str,
-    ) -> Result<CiphertextNative, String> {
-        Self::encrypt_field(&private_key.seed(), secret, "private_key")
+    ) -> Result<PrivateKeyCiphertext, AleoError> {
+        let kdf = KdfParams::new();
+        let key = Self::derive_key(secret, &kdf)?;
+        let ciphertext = Self::encrypt_field(&private_key.seed(), key, "private_key")?;
+        Ok(PrivateKeyCiphertext::new(ciphertext, Some(kdf)))
     }
 
-    /// Decrypt a private key from ciphertext using a secret
+    /// Decrypt a private key from ciphertext using a secret. Ciphertexts created before the key
+    /// derivation function was introduced are decrypted with the unstretched secret
     pub(crate) fn decrypt_private_key_with_secret(
-        ciphertext: &CiphertextNative,
+        ciphertext: &PrivateKeyCiphertext,
         secret: &str,
-    ) -> Result<PrivateKeyNative, String> {
-        let seed = Self::decrypt_field(ciphertext, secret, "private_key")?;
-        PrivateKeyNative::try_from(seed).map_err(|e| e.to_string())
+    ) -> Result<PrivateKeyNative, AleoError> {
+        let key = match ciphertext.kdf() {
+            Some(kdf) => Self::derive_key(secret, kdf)?,
+            None => FieldNative::new_domain_separator(secret),
+        };
+        Self::decrypt_private_key_with_key(ciphertext, key)
+    }
+
+    /// Decrypt a private key from ciphertext using a key already derived from the secret
+    pub(crate) fn decrypt_private_key_with_key(
+        ciphertext: &PrivateKeyCiphertext,
+        key: FieldNative,
+    ) -> Result<PrivateKeyNative, AleoError> {
+        let seed = Self::decrypt_field(ciphertext, key, "private_key")?;
+        PrivateKeyNative::try_from(seed).map_err(AleoError::invalid_input)
+    }
+
+    /// Stretch a secret into a field element key with the scrypt key derivation function
+    pub(crate) fn derive_key(secret: &str, kdf: &KdfParams) -> Result<FieldNative, AleoError> {
+        // The parameters come from untrusted ciphertexts, so bound the memory scrypt would allocate
+        // and the work it would run before running it, rather than letting an allocation a browser
+        // cannot grow to abort the wasm instance or a long derivation hang it
+        if kdf.log_n > SCRYPT_MAX_LOG_N || kdf.r > SCRYPT_MAX_R || kdf.p > SCRYPT_MAX_P {
+            return Err(AleoError::invalid_input("The key derivation cost exceeds the maximum supported cost"));
+        }
+        let blocks = u64::from(kdf.r) * (1u64 << kdf.log_n);
+        if 128 * blocks > SCRYPT_MAX_MEMORY {
+            return Err(AleoError::invalid_input("The key derivation memory cost exceeds the maximum supported cost"));
+        }
+        if u64::from(kdf.p) * blocks > SCRYPT_MAX_WORK {
+            return Err(AleoError::invalid_input("The key derivation work exceeds the maximum supported cost"));
+        }
+        let salt = hex::decode(&kdf.salt).map_err(|_| AleoError::parse("The key derivation salt is not valid hex"))?;
+        let params = scrypt::Params::new(kdf.log_n, kdf.r, kdf.p, 32)
+            .map_err(|_| AleoError::invalid_input("Invalid key derivation parameters"))?;
+        let mut key = [0u8; 32];
+        scrypt::scrypt(secret.as_bytes(), &salt, &params, &mut key)
+            .map_err(|_| AleoError::invalid_input("Key derivation failed"))?;
+        Ok(FieldNative::new(<CurrentNetwork as Environment>::Field::from_bytes_le_mod_order(&key)))
+    }
+
+    // Encrypt a plaintext under a blinding factor derived from the domain, a fresh nonce and the key.
+    // A check value derived from the blinding factor is encrypted alongside the content so that
+    // decryption with the wrong key is detected
+    fn encrypt_content(
+        content: PlaintextNative,
+        content_kind: &str,
+        domain: &str,
+        key: EncryptionKey,
+    ) -> Result<SymmetricCiphertext, AleoError> {
+        let (secret, kdf) = match key {
+            EncryptionKey::Password(password) => {
+                let kdf = KdfParams::new();
+                (Self::derive_key(password, &kdf)?, Some(kdf))
+            }
+            EncryptionKey::Field(field) => (field, None),
+        };
+        let domain_separator = FieldNative::new_domain_separator(domain);
+
+        // Generate a nonce
+        let mut rng = rand::thread_rng();
+        let nonce = Uniform::rand(&mut rng);
+
+        // Derive a blinding factor and create an encryption target
+        let blinding =
+            CurrentNetwork::hash_psd2(&[domain_separator, nonce, secret]).map_err(AleoError::invalid_input)?;
+        let check = CurrentNetwork::hash_psd2(&[domain_separator, blinding]).map_err(AleoError::invalid_input)?;
+        let plaintext = PlaintextNative::Struct(
+            indexmap::IndexMap::from_iter(vec![
+                (IdentifierNative::from_str("data").map_err(AleoError::parse)?, content),
+                (
+                    IdentifierNative::from_str("check").map_err(AleoError::parse)?,
+                    PlaintextNative::from(LiteralNative::Field(check)),
+                ),
+            ]),
+            OnceCell::new(),
+        );
+        let ciphertext = plaintext.encrypt_symmetric(blinding).map_err(AleoError::invalid_input)?;
+        Ok(SymmetricCiphertext::new(content_kind, domain, kdf, nonce, ciphertext))
+    }
+
+    // Recover a plaintext encrypted by `encrypt_content`
+    fn decrypt_content(
+        ciphertext: &SymmetricCiphertext,
+        content_kind: &str,
+        key: EncryptionKey,
+    ) -> Result<PlaintextNative, AleoError> {
+        if ciphertext.content() != content_kind {
+            return Err(AleoError::invalid_input(format!(
+                "Expected a ciphertext of {content_kind} but found {}",
+                ciphertext.content()
+            )));
+        }
+        let secret = match (key, ciphertext.kdf()) {
+            (EncryptionKey::Password(password), Some(kdf)) => Self::derive_key(password, kdf)?,
+            (EncryptionKey::Field(field), None) => field,
+            (EncryptionKey::Password(_), None) => {
+                return Err(AleoError::invalid_input("Ciphertext was encrypted with a field key"));
+            }
+            (EncryptionKey::Field(_), Some(_)) => {
+                return Err(AleoError::invalid_input("Ciphertext was encrypted with a password"));
+            }
+        };
+        let domain_separator = FieldNative::new_domain_separator(&ciphertext.domain());
+        let blinding = CurrentNetwork::hash_psd2(&[domain_separator, ciphertext.nonce(), secret])
+            .map_err(AleoError::invalid_input)?;
+        let check = CurrentNetwork::hash_psd2(&[domain_separator, blinding]).map_err(AleoError::invalid_input)?;
+        let decryption_error = || AleoError::invalid_input("Decryption failed - the key did not match the ciphertext");
+        let decrypted = ciphertext.ciphertext().decrypt_symmetric(blinding).map_err(|_| decryption_error())?;
+        if Self::extract_value(&decrypted, "check").map_err(|_| decryption_error())? != check {
+            return Err(decryption_error());
+        }
+        let identity = IdentifierNative::from_str("data").map_err(AleoError::parse)?;
+        decrypted.find(&[identity]).map_err(AleoError::invalid_input)
+    }
+
+    // Pack bytes into an array of field elements prefixed with the number of bytes
+    fn bytes_to_plaintext(bytes: &[u8]) -> Result<PlaintextNative, AleoError> {
+        if bytes.len() > MAX_BYTES_LENGTH {
+            return Err(AleoError::invalid_input(format!(
+                "Byte blobs can hold at most {MAX_BYTES_LENGTH} bytes, found {}",
+                bytes.len()
+            )));
+        }
+        let mut elements = vec![PlaintextNative::from(LiteralNative::Field(FieldNative::from_u64(bytes.len() as u64)))];
+        elements.extend(bytes.chunks(BYTES_PER_FIELD).map(|chunk| {
+            let field = FieldNative::new(<CurrentNetwork as Environment>::Field::from_bytes_le_mod_order(chunk));
+            PlaintextNative::from(LiteralNative::Field(field))
+        }));
+        Ok(PlaintextNative::Array(elements, OnceCell::new()))
+    }
+
+    // Pack a view key into a field element
+    fn view_key_to_plaintext(view_key: &ViewKey) -> Result<PlaintextNative, AleoError> {
+        let bytes = view_key.to_bytes_le().map_err(AleoError::invalid_input)?;
+        let field = FieldNative::new(<CurrentNetwork as Environment>::Field::from_bytes_le_mod_order(&bytes));
+        Ok(PlaintextNative::from(LiteralNative::Field(field)))
+    }
+
+    // Unpack a view key from a field element produced by `view_key_to_plaintext`
+    fn plaintext_to_view_key(plaintext: &PlaintextNative) -> Result<ViewKey, AleoError> {
+        let bytes = Self::literal_field(plaintext)?.to_bytes_le().map_err(AleoError::invalid_input)?;
+        let view_key = ViewKeyNative::from_bytes_le(&bytes)
+            .map_err(|_| AleoError::invalid_input("Ciphertext was not a view key"))?;
+        Ok(ViewKey::from(view_key))
+    }
+
+    // Unpack bytes from an array of field elements produced by `bytes_to_plaintext`
+    fn plaintext_to_bytes(plaintext: &PlaintextNative) -> Result<Vec<u8>, AleoError> {
+        let elements = match plaintext {
+            PlaintextNative::Array(elements, ..) if !elements.is_empty() => elements,
+            _ => return Err(AleoError::invalid_input("Ciphertext did not contain bytes")),
+        };
+        let length_bytes = Self::literal_field(&elements[0])?.to_bytes_le().map_err(AleoError::invalid_input)?;
+        let length_bytes: [u8; 8] =
+            length_bytes[..8].try_into().map_err(|_| AleoError::invalid_input("Invalid byte length"))?;
+        let length = u64::from_le_bytes(length_bytes) as usize;
+        let mut bytes = Vec::with_capacity((elements.len() - 1) * BYTES_PER_FIELD);
+        for element in &elements[1..] {
+            let field_bytes = Self::literal_field(element)?.to_bytes_le().map_err(AleoError::invalid_input)?;
+            bytes.extend_from_slice(&field_bytes[..BYTES_PER_FIELD]);
+        }
+        if length > bytes.len() {
+            return Err(AleoError::invalid_input("Ciphertext did not contain bytes"));
+        }
+        bytes.truncate(length);
+        Ok(bytes)
+    }
+
+    // Extract a field element from a literal plaintext
+    fn literal_field(plaintext: &PlaintextNative) -> Result<FieldNative, AleoError> {
+        match plaintext {
+            PlaintextNative::Literal(LiteralNative::Field(field), ..) => Ok(*field),
+            _ => Err(AleoError::invalid_input("Expected a field literal")),
+        }
     }
 
     // Encrypted a field element into a ciphertext representation
-    fn encrypt_field(field: &FieldNative, secret: &str, domain: &str) -> Result<CiphertextNative, String> {
-        // Derive the domain separators and the secret.
+    fn encrypt_field(field: &FieldNative, secret: FieldNative, domain: &str) -> Result<CiphertextNative, AleoError> {
+        // Derive the domain separator.
         let domain = FieldNative::new_domain_separator(domain);
-        let secret = FieldNative::new_domain_separator(secret);
 
         // Generate a nonce
         let mut rng = rand::thread_rng();
         let nonce = Uniform::rand(&mut rng);
 
         // Derive a blinding factor and create an encryption target
-        let blinding = CurrentNetwork::hash_psd2(&[domain, nonce, secret]).map_err(|e| e.to_string())?;
+        let blinding = CurrentNetwork::hash_psd2(&[domain, nonce, secret]).map_err(AleoError::invalid_input)?;
         let key = blinding * field;
         let plaintext = PlaintextNative::Struct(
             indexmap::IndexMap::from_iter(vec![
                 (
-                    IdentifierNative::from_str("key").map_err(|e| e.to_string())?,
+                    IdentifierNative::from_str("key").map_err(AleoError::parse)?,
                     PlaintextNative::from(LiteralNative::Field(key)),
                 ),
                 (
-                    IdentifierNative::from_str("nonce").map_err(|e| e.to_string())?,
+                    IdentifierNative::from_str("nonce").map_err(AleoError::parse)?,
                     PlaintextNative::from(LiteralNative::Field(nonce)),
                 ),
             ]),
             OnceCell::new(),
         );
-        plaintext.encrypt_symmetric(secret).map_err(|e| e.to_string())
+        plaintext.encrypt_symmetric(secret).map_err(AleoError::invalid_input)
     }
 
     // Recover a field element encrypted within ciphertext
-    fn decrypt_field(ciphertext: &CiphertextNative, secret: &str, domain: &str) -> Result<FieldNative, String> {
+    fn decrypt_field(
+        ciphertext: &CiphertextNative,
+        secret: FieldNative,
+        domain: &str,
+    ) -> Result<FieldNative, AleoError> {
         let domain = FieldNative::new_domain_separator(domain);
-        let secret = FieldNative::new_domain_separator(secret);
-        let decrypted = ciphertext.decrypt_symmetric(secret).map_err(|e| e.to_string())?;
+        let decrypted = ciphertext.decrypt_symmetric(secret).map_err(AleoError::invalid_input)?;
         let recovered_key = Self::extract_value(&decrypted, "key")?;
         let recovered_nonce = Self::extract_value(&decrypted, "nonce")?;
         let recovered_blinding =
-            CurrentNetwork::hash_psd2(&[domain, recovered_nonce, secret]).map_err(|e| e.to_string())?;
+            CurrentNetwork::hash_psd2(&[domain, recovered_nonce, secret]).map_err(AleoError::invalid_input)?;
         Ok(recovered_key / recovered_blinding)
     }
 
     // Extract a field element from a plaintext
-    fn extract_value(plaintext: &PlaintextNative, identifier: &str) -> Result<FieldNative, String> {
-        let identity = IdentifierNative::from_str(identifier).map_err(|e| e.to_string())?;
-        let value = plaintext.find(&[identity]).map_err(|e| e.to_string())?;
+    fn extract_value(plaintext: &PlaintextNative, identifier: &str) -> Result<FieldNative, AleoError> {
+        let identity = IdentifierNative::from_str(identifier).map_err(AleoError::parse)?;
+        let value = plaintext.find(&[identity]).map_err(AleoError::invalid_input)?;
         match value {
             PlaintextNative::Literal(literal, ..) => match literal {
                 LiteralNative::Field(recovered_value) => Ok(recovered_value),
-                _ => Err("Wrong literal type".to_string()),
+                _ => Err(AleoError::invalid_input("Wrong literal type")),
             },
-            _ => Err("Expected literal".to_string()),
+            _ => Err(AleoError::invalid_input("Expected literal")),
         }
     }
 }
@@ -158,6 +609,143 @@ mod tests {
         assert_eq!(recovered_key_1, recovered_key_2);
     }
 
+    #[wasm_bindgen_test]
+    fn test_encryptor_uses_key_derivation_function() {
+        let mut rng = TestRng::default();
+        let private_key = PrivateKeyNative::new(&mut rng).unwrap();
+        let enc = Encryptor::encrypt_private_key_with_secret(&private_key, "mypassword").unwrap();
+        let enc2 = Encryptor::encrypt_private_key_with_secret(&private_key, "mypassword").unwrap();
+
+        // Assert that every ciphertext is tagged with the key derivation parameters and a fresh salt
+        let kdf = enc.kdf().unwrap();
+        assert_eq!((kdf.log_n, kdf.r, kdf.p), (SCRYPT_LOG_N, SCRYPT_R, SCRYPT_P));
+        assert_eq!(hex::decode(&kdf.salt).unwrap().len(), SALT_LENGTH);
+        assert_ne!(kdf.salt, enc2.kdf().unwrap().salt);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_encryptor_derive_key() {
+        let kdf = KdfParams { log_n: 10, r: 8, p: 1, salt: "00112233445566778899aabbccddeeff".to_string() };
+        let key = Encryptor::derive_key("mypassword", &kdf).unwrap();
+
+        // Assert that key derivation is deterministic and depends on the secret and the salt
+        assert_eq!(key, Encryptor::derive_key("mypassword", &kdf).unwrap());
+        assert_ne!(key, Encryptor::derive_key("mypassword2", &kdf).unwrap());
+        let other_salt = KdfParams { salt: "ffeeddccbbaa99887766554433221100".to_string(), ..kdf.clone() };
+        assert_ne!(key, Encryptor::derive_key("mypassword", &other_salt).unwrap());
+
+        // Assert that invalid parameters are rejected
+        assert!(
+            Encryptor::derive_key("mypassword", &KdfParams { salt: "not hex".to_string(), ..kdf.clone() }).is_err()
+        );
+        assert!(
+            Encryptor::derive_key("mypassword", &KdfParams { log_n: SCRYPT_MAX_LOG_N + 1, ..kdf.clone() }).is_err()
+        );
+        assert!(Encryptor::derive_key("mypassword", &KdfParams { r: SCRYPT_MAX_R + 1, ..kdf.clone() }).is_err());
+        assert!(Encryptor::derive_key("mypassword", &KdfParams { p: SCRYPT_MAX_P + 1, ..kdf.clone() }).is_err());
+
+        // Assert that parameters within their individual bounds are rejected when their combined
+        // memory cost is too high (1 GiB), without attempting the allocation
+        let error = Encryptor::derive_key("mypassword", &KdfParams { log_n: SCRYPT_MAX_LOG_N, r: 8, ..kdf.clone() })
+            .unwrap_err();
+        assert!(error.message().contains("memory"));
+
+        // Assert that parameters within the memory bound are rejected when the parallelization makes
+        // the total work too high (256 MiB run 16 times)
+        let error =
+            Encryptor::derive_key("mypassword", &KdfParams { log_n: 18, r: 8, p: 16, ..kdf.clone() }).unwrap_err();
+        assert!(error.message().contains("work"));
+        let error =
+            Encryptor::derive_key("mypassword", &KdfParams { log_n: 20, r: 8, p: 16, ..kdf.clone() }).unwrap_err();
+        assert!(error.message().contains("memory"));
+        assert!(Encryptor::derive_key("mypassword", &KdfParams { p: 2, ..kdf }).is_ok());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_encryptor_plaintext_round_trip() {
+        let plaintext = PlaintextNative::from_str("{ amount: 100u64, flags: [true, false] }").unwrap().to_string();
+        let ciphertext = Encryptor::encrypt_plaintext(&plaintext, "mypassword", "my_app").unwrap();
+        assert_eq!(Encryptor::decrypt_plaintext(&ciphertext, "mypassword").unwrap(), plaintext);
+        assert!(Encryptor::decrypt_plaintext(&ciphertext, "wrong_password").is_err());
+
+        let key = Field::from_string("1234field").unwrap();
+        let ciphertext = Encryptor::encrypt_plaintext_with_key(&plaintext, &key, "my_app").unwrap();
+        assert_eq!(Encryptor::decrypt_plaintext_with_key(&ciphertext, &key).unwrap(), plaintext);
+        let wrong_key = Field::from_string("4321field").unwrap();
+        assert!(Encryptor::decrypt_plaintext_with_key(&ciphertext, &wrong_key).is_err());
+        assert!(Encryptor::decrypt_plaintext(&ciphertext, "mypassword").is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_encryptor_bytes_round_trip() {
+        let key = Field::from_string("1234field").unwrap();
+        for length in [0, 1, 30, 31, 32, 62, 100, MAX_BYTES_LENGTH] {
+            let bytes = (0..length).map(|i| (i * 7) as u8).collect::<Vec<u8>>();
+            let ciphertext = Encryptor::encrypt_bytes_with_key(&bytes, &key, "my_app").unwrap();
+            assert_eq!(Encryptor::decrypt_bytes_with_key(&ciphertext, &key).unwrap(), bytes);
+        }
+        let ciphertext = Encryptor::encrypt_bytes(&[255; 40], "mypassword", "my_app").unwrap();
+        assert_eq!(Encryptor::decrypt_bytes(&ciphertext, "mypassword").unwrap(), vec![255; 40]);
+
+        // Assert that ciphertexts can only be decrypted as the content they hold
+        assert!(Encryptor::decrypt_plaintext(&ciphertext, "mypassword").is_err());
+
+        // Assert that blobs over the maximum length are rejected instead of producing ciphertexts
+        // that cannot be decrypted
+        assert_eq!(MAX_BYTES_LENGTH, 961);
+        assert!(Encryptor::encrypt_bytes_with_key(&[1; MAX_BYTES_LENGTH + 1], &key, "my_app").is_err());
+        assert!(Encryptor::encrypt_bytes(&[1; MAX_BYTES_LENGTH + 1], "mypassword", "my_app").is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_encryptor_view_key_round_trip() {
+        let mut rng = TestRng::default();
+        let view_key = ViewKey::from(ViewKeyNative::try_from(PrivateKeyNative::new(&mut rng).unwrap()).unwrap());
+        let ciphertext = Encryptor::encrypt_view_key(&view_key, "mypassword", "my_wallet").unwrap();
+        assert_eq!(ciphertext.content(), VIEW_KEY_CONTENT);
+        assert_eq!(ciphertext.domain(), "my_wallet");
+        assert_eq!(Encryptor::decrypt_view_key(&ciphertext, "mypassword").unwrap(), view_key);
+        assert!(Encryptor::decrypt_view_key(&ciphertext, "wrong_password").is_err());
+
+        let key = Field::from_string("1234field").unwrap();
+        let ciphertext = Encryptor::encrypt_view_key_with_key(&view_key, &key, "my_wallet").unwrap();
+        assert_eq!(Encryptor::decrypt_view_key_with_key(&ciphertext, &key).unwrap(), view_key);
+        assert!(Encryptor::decrypt_view_key_with_key(&ciphertext, &Field::from_string("1235field").unwrap()).is_err());
+        assert!(Encryptor::decrypt_view_key(&ciphertext, "mypassword").is_err());
+
+        // Assert that view key ciphertexts are not accepted as other content
+        assert!(Encryptor::decrypt_plaintext_with_key(&ciphertext, &key).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_encryptor_plaintext_for_address_round_trip() {
+        let mut rng = TestRng::default();
+        let view_key = ViewKey::from(ViewKeyNative::try_from(PrivateKeyNative::new(&mut rng).unwrap()).unwrap());
+        let other_view_key = ViewKey::from(ViewKeyNative::try_from(PrivateKeyNative::new(&mut rng).unwrap()).unwrap());
+        let plaintext = PlaintextNative::from_str("{ memo: 42field, paid: true }").unwrap().to_string();
+
+        let ciphertext = Encryptor::encrypt_plaintext_for_address(&plaintext, &view_key.to_address(), "memo").unwrap();
+        assert_eq!(Encryptor::decrypt_plaintext_with_view_key(&ciphertext, &view_key).unwrap(), plaintext);
+
+        // Assert that only the recipient can decrypt the ciphertext and that every encryption is fresh
+        assert!(Encryptor::decrypt_plaintext_with_view_key(&ciphertext, &other_view_key).is_err());
+        let ciphertext2 = Encryptor::encrypt_plaintext_for_address(&plaintext, &view_key.to_address(), "memo").unwrap();
+        assert_ne!(ciphertext, ciphertext2);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_encryptor_domains_separate_ciphertexts() {
+        let key = Field::from_string("1234field").unwrap();
+        let ciphertext = Encryptor::encrypt_plaintext_with_key("1u8", &key, "my_app").unwrap();
+
+        // Assert that changing the domain recorded in the ciphertext prevents decryption
+        let tampered = SymmetricCiphertext::from_string(
+            &ciphertext.to_string().replace("\"domain\":\"my_app\"", "\"domain\":\"other_app\""),
+        )
+        .unwrap();
+        assert!(Encryptor::decrypt_plaintext_with_key(&tampered, &key).is_err());
+    }
+
     #[wasm_bindgen_test]
     fn test_encryptor_different_private_keys_encrypted_with_same_password_dont_match() {
         let mut rng = TestRng::default();
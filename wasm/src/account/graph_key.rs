@@ -0,0 +1,98 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    account::{PrivateKey, ViewKey},
+    types::Field,
+    AleoError,
+};
+
+use crate::types::native::{GraphKeyNative, ViewKeyNative};
+use core::{convert::TryFrom, ops::Deref};
+use wasm_bindgen::prelude::*;
+
+/// Graph key of an Aleo account. The graph key is derived from the view key and can compute the
+/// tags of the records owned by the account, which lets watch-only wallets detect spent records
+/// without access to the private key
+#[wasm_bindgen]
+#[derive(Clone, PartialEq, Eq)]
+pub struct GraphKey(GraphKeyNative);
+
+#[wasm_bindgen]
+impl GraphKey {
+    /// Create a new graph key from a view key
+    ///
+    /// @param {ViewKey} view_key View key
+    /// @returns {GraphKey} Graph key
+    #[wasm_bindgen(js_name = "fromViewKey")]
+    pub fn from_view_key(view_key: &ViewKey) -> Result<GraphKey, AleoError> {
+        Ok(Self(GraphKeyNative::try_from(**view_key).map_err(AleoError::invalid_input)?))
+    }
+
+    /// Create a new graph key from a private key
+    ///
+    /// @param {PrivateKey} private_key Private key
+    /// @returns {GraphKey} Graph key
+    #[wasm_bindgen(js_name = "fromPrivateKey")]
+    pub fn from_private_key(private_key: &PrivateKey) -> Result<GraphKey, AleoError> {
+        let view_key = ViewKeyNative::try_from(**private_key).map_err(AleoError::invalid_input)?;
+        Ok(Self(GraphKeyNative::try_from(view_key).map_err(AleoError::invalid_input)?))
+    }
+
+    /// Get the tag secret key of the graph key
+    ///
+    /// @returns {Field} sk_tag
+    #[wasm_bindgen(js_name = "skTag")]
+    pub fn sk_tag(&self) -> Field {
+        Field::from(self.0.sk_tag())
+    }
+}
+
+impl From<GraphKeyNative> for GraphKey {
+    fn from(graph_key: GraphKeyNative) -> Self {
+        Self(graph_key)
+    }
+}
+
+impl From<GraphKey> for GraphKeyNative {
+    fn from(graph_key: GraphKey) -> Self {
+        graph_key.0
+    }
+}
+
+impl Deref for GraphKey {
+    type Target = GraphKeyNative;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    pub fn test_graph_key_matches_view_key_derivation() {
+        let private_key = PrivateKey::new();
+        let graph_key = GraphKey::from_view_key(&private_key.to_view_key()).unwrap();
+        assert!(graph_key == GraphKey::from_private_key(&private_key).unwrap());
+        assert!(graph_key != GraphKey::from_private_key(&PrivateKey::new()).unwrap());
+        assert_eq!(graph_key.sk_tag(), GraphKey::from_private_key(&private_key).unwrap().sk_tag());
+    }
+}
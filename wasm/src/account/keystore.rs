@@ -0,0 +1,231 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    account::{Address, Encryptor, KdfParams, PrivateKey, PrivateKeyCiphertext},
+    AleoError,
+};
+
+use crate::types::native::CiphertextNative;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use wasm_bindgen::prelude::*;
+
+/// Version of the keystore format
+const KEYSTORE_VERSION: u8 = 1;
+/// Name of the cipher used to encrypt the private key
+const KEYSTORE_CIPHER: &str = "aleo-symmetric";
+/// Name of the key derivation function used to stretch the password
+const KEYSTORE_KDF: &str = "scrypt";
+
+/// Encrypted private key and the parameters needed to decrypt it
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+struct KeystoreCrypto {
+    cipher: String,
+    ciphertext: String,
+    kdf: String,
+    kdfparams: KdfParams,
+}
+
+/// Encrypted JSON keystore holding a single Aleo private key. Similar to the Ethereum v3 keystore,
+/// it stores the public address in the clear so accounts can be listed without decrypting them
+///
+/// ```json
+/// {
+///   "version": 1,
+///   "address": "aleo1...",
+///   "crypto": {
+///     "cipher": "aleo-symmetric",
+///     "ciphertext": "ciphertext1...",
+///     "kdf": "scrypt",
+///     "kdfparams": { "log_n": 15, "r": 8, "p": 1, "salt": "..." }
+///   },
+///   "created": "2024-01-01T00:00:00.000Z"
+/// }
+/// ```
+#[wasm_bindgen]
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Keystore {
+    version: u8,
+    address: String,
+    crypto: KeystoreCrypto,
+    created: String,
+}
+
+#[wasm_bindgen]
+impl Keystore {
+    /// Encrypt a private key into a keystore using a password
+    ///
+    /// @param {PrivateKey} private_key Private key to encrypt
+    /// @param {string} password Password to encrypt the private key with
+    /// @returns {Keystore} Keystore holding the encrypted private key
+    pub fn encrypt(private_key: &PrivateKey, password: &str) -> Result<Keystore, AleoError> {
+        let created = String::from(js_sys::Date::new_0().to_iso_string());
+        Self::new(private_key, password, created)
+    }
+
+    /// Decrypt the private key held by the keystore
+    ///
+    /// @param {string} password Password the private key was encrypted with
+    /// @returns {PrivateKey} Private key
+    pub fn decrypt(&self, password: &str) -> Result<PrivateKey, AleoError> {
+        let ciphertext = CiphertextNative::from_str(&self.crypto.ciphertext)
+            .map_err(|_| AleoError::parse("Invalid keystore ciphertext"))?;
+        let ciphertext = PrivateKeyCiphertext::new(ciphertext, Some(self.crypto.kdfparams.clone()));
+        // Errors from the key derivation parameters are reported as is, only a key that does not
+        // decrypt the private key means the password is incorrect
+        let key = Encryptor::derive_key(password, &self.crypto.kdfparams)?;
+        let private_key = Encryptor::decrypt_private_key_with_key(&ciphertext, key)
+            .map_err(|_| AleoError::invalid_input("Decryption failed - the password is incorrect"))?;
+        let private_key = PrivateKey::from(private_key);
+        if private_key.to_address().to_string() != self.address {
+            return Err(AleoError::invalid_input("Decryption failed - the password is incorrect"));
+        }
+        Ok(private_key)
+    }
+
+    /// Returns the address of the account held by the keystore
+    ///
+    /// @returns {Address} Address
+    pub fn address(&self) -> Result<Address, AleoError> {
+        Address::from_string(&self.address)
+    }
+
+    /// Returns the ISO 8601 time at which the keystore was created
+    ///
+    /// @returns {string} Creation time
+    pub fn created(&self) -> String {
+        self.created.clone()
+    }
+
+    /// Returns the version of the keystore format
+    ///
+    /// @returns {number} Keystore version
+    pub fn version(&self) -> u8 {
+        self.version
+    }
+
+    /// Returns the JSON string representation of the keystore
+    ///
+    /// @returns {string} Keystore JSON
+    #[allow(clippy::inherent_to_string)]
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_string(&self) -> String {
+        serde_json::to_string(&self).unwrap()
+    }
+
+    /// Read a keystore from its JSON string representation
+    ///
+    /// @param {string} keystore Keystore JSON
+    /// @returns {Keystore} Keystore
+    #[wasm_bindgen(js_name = fromString)]
+    pub fn from_string(keystore: &str) -> Result<Keystore, AleoError> {
+        let keystore: Keystore = serde_json::from_str(keystore).map_err(AleoError::parse)?;
+        if keystore.version != KEYSTORE_VERSION {
+            return Err(AleoError::parse(format!("Unsupported keystore version {}", keystore.version)));
+        }
+        if keystore.crypto.cipher != KEYSTORE_CIPHER || keystore.crypto.kdf != KEYSTORE_KDF {
+            return Err(AleoError::parse(format!(
+                "Unsupported keystore cipher {} with kdf {}",
+                keystore.crypto.cipher, keystore.crypto.kdf
+            )));
+        }
+        keystore.address()?;
+        Ok(keystore)
+    }
+}
+
+impl Keystore {
+    /// Encrypt a private key into a keystore created at the given time
+    pub(crate) fn new(private_key: &PrivateKey, password: &str, created: String) -> Result<Self, AleoError> {
+        let ciphertext = Encryptor::encrypt_private_key_with_secret(private_key, password)
+            .map_err(|_| AleoError::invalid_input("Encryption failed"))?;
+        let kdfparams = ciphertext.kdf().cloned().ok_or_else(|| AleoError::invalid_input("Encryption failed"))?;
+        Ok(Self {
+            version: KEYSTORE_VERSION,
+            address: private_key.to_address().to_string(),
+            crypto: KeystoreCrypto {
+                cipher: KEYSTORE_CIPHER.to_string(),
+                ciphertext: (*ciphertext).to_string(),
+                kdf: KEYSTORE_KDF.to_string(),
+                kdfparams,
+            },
+            created,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use wasm_bindgen_test::*;
+
+    const CREATED: &str = "2024-01-01T00:00:00.000Z";
+
+    #[wasm_bindgen_test]
+    fn test_keystore_round_trip() {
+        let private_key = PrivateKey::new();
+        let keystore = Keystore::new(&private_key, "mypassword", CREATED.to_string()).unwrap();
+        let keystore = Keystore::from_string(&keystore.to_string()).unwrap();
+
+        // Assert the account can be listed without decrypting the keystore
+        assert_eq!(keystore.address().unwrap(), private_key.to_address());
+        assert_eq!(keystore.created(), CREATED);
+        assert_eq!(keystore.version(), KEYSTORE_VERSION);
+
+        assert_eq!(keystore.decrypt("mypassword").unwrap(), private_key);
+        assert!(keystore.decrypt("wrong_password").is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_keystore_rejects_invalid_json() {
+        let keystore = Keystore::new(&PrivateKey::new(), "mypassword", CREATED.to_string()).unwrap().to_string();
+        assert!(Keystore::from_string("garbage").is_err());
+        assert!(Keystore::from_string(&keystore.replace("\"version\":1", "\"version\":3")).is_err());
+        assert!(Keystore::from_string(&keystore.replace("\"scrypt\"", "\"pbkdf2\"")).is_err());
+        assert!(Keystore::from_string(&keystore.replace("\"address\":\"aleo1", "\"address\":\"aleo2")).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_keystore_detects_mismatched_address() {
+        let keystore = Keystore::new(&PrivateKey::new(), "mypassword", CREATED.to_string()).unwrap();
+        let other_address = PrivateKey::new().to_address().to_string();
+        let tampered = keystore.to_string().replace(&keystore.address, &other_address);
+        assert!(Keystore::from_string(&tampered).unwrap().decrypt("mypassword").is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_keystore_rejects_oversized_key_derivation_cost() {
+        let keystore = Keystore::new(&PrivateKey::new(), "mypassword", CREATED.to_string()).unwrap().to_string();
+        let tampered = keystore.replace("\"log_n\":15,\"r\":8", "\"log_n\":20,\"r\":16");
+        assert_ne!(tampered, keystore);
+        let error = Keystore::from_string(&tampered).unwrap().decrypt("mypassword").unwrap_err();
+        assert_eq!(error.message(), "The key derivation memory cost exceeds the maximum supported cost");
+    }
+
+    #[wasm_bindgen_test]
+    fn test_keystore_reports_invalid_salt() {
+        let keystore = Keystore::new(&PrivateKey::new(), "mypassword", CREATED.to_string()).unwrap();
+        let salt = keystore.crypto.kdfparams.salt.clone();
+        let tampered = keystore.to_string().replace(&salt, "not hex");
+        let error = Keystore::from_string(&tampered).unwrap().decrypt("mypassword").unwrap_err();
+        assert_eq!(error.code(), crate::ErrorCode::Parse);
+        assert_eq!(error.message(), "The key derivation salt is not valid hex");
+        let error = keystore.decrypt("wrong_password").unwrap_err();
+        assert_eq!(error.message(), "Decryption failed - the password is incorrect");
+    }
+}
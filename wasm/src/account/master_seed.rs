@@ -0,0 +1,234 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{account::PrivateKey, AleoError};
+
+use crate::types::native::{CurrentNetwork, Environment, FieldNative, Network, PrimeField, PrivateKeyNative, ToBytes};
+use core::convert::TryInto;
+use js_sys::Array;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use wasm_bindgen::prelude::*;
+
+/// Domain separator used to derive the master key from a master seed
+const HD_MASTER_KEY_DOMAIN: &str = "AleoHDMasterKey";
+
+/// Domain separator used to derive child keys from the master key
+const HD_CHILD_KEY_DOMAIN: &str = "AleoHDChildKey";
+
+/// Number of master seed bytes packed into each field element, which keeps every chunk below the modulus
+const SEED_BYTES_PER_FIELD: usize = 31;
+
+/// Maximum number of child accounts derived by a single call to `deriveChildren`
+const MAX_DERIVED_CHILDREN: u32 = 1_000;
+
+/// Master seed from which any number of indexed Aleo accounts can be deterministically derived,
+/// allowing a single backup of the seed to recover every account derived from it.
+///
+/// The child private key at path `m/{index}` is derived as follows:
+/// 1. The master seed is split into chunks of 31 bytes, each read as a little-endian field element
+/// 2. `master = hash_psd2([domain("AleoHDMasterKey"), length, chunk_0, chunk_1, ...])`, where `length`
+/// is the length of the master seed in bytes
+/// 3. `child = hash_psd2([domain("AleoHDChildKey"), master, index])`
+/// 4. The 32 byte little-endian encoding of `child` is converted into a private key in the same
+/// way as `PrivateKey.from_seed_unchecked`
+#[wasm_bindgen]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MasterSeed(Vec<u8>);
+
+#[wasm_bindgen]
+impl MasterSeed {
+    /// Generate a new 32 byte master seed using a cryptographically secure random number generator
+    ///
+    /// @returns {MasterSeed}
+    #[wasm_bindgen(constructor)]
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self(StdRng::from_entropy().gen::<[u8; 32]>().to_vec())
+    }
+
+    /// Get a master seed from a series of bytes
+    ///
+    /// @param {Uint8Array} seed Uint8Array between 16 and 64 bytes long acting as the master seed
+    /// @returns {MasterSeed}
+    #[wasm_bindgen(js_name = fromBytes)]
+    pub fn from_bytes(seed: &[u8]) -> Result<MasterSeed, AleoError> {
+        if !(16..=64).contains(&seed.len()) {
+            return Err(AleoError::parse("The master seed must be between 16 and 64 bytes long"));
+        }
+        Ok(Self(seed.to_vec()))
+    }
+
+    /// Get a master seed from a BIP-39 mnemonic phrase of 12 or 24 English words. The seed is the
+    /// same one `PrivateKey.fromMnemonic` recovers from the phrase and passphrase
+    ///
+    /// @param {string} mnemonic Space separated mnemonic phrase of 12 or 24 words
+    /// @param {string | undefined} passphrase Optional passphrase protecting the mnemonic
+    /// @returns {MasterSeed}
+    #[wasm_bindgen(js_name = fromMnemonic)]
+    pub fn from_mnemonic(mnemonic: &str, passphrase: Option<String>) -> Result<MasterSeed, AleoError> {
+        let seed = PrivateKey::mnemonic_to_seed(mnemonic, passphrase.as_deref())?;
+        Ok(Self(seed.to_bytes_le().map_err(AleoError::invalid_input)?))
+    }
+
+    /// Get the byte representation of the master seed. This function should be used very carefully
+    /// as the master seed recovers every account derived from it
+    ///
+    /// @returns {Uint8Array} Byte representation of the master seed
+    #[wasm_bindgen(js_name = toBytes)]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.0.clone()
+    }
+
+    /// Derive the private key of the child account at path `m/{index}`
+    ///
+    /// @param {number} index Index of the child account
+    /// @returns {PrivateKey} Private key of the child account
+    #[wasm_bindgen(js_name = deriveChild)]
+    pub fn derive_child(&self, index: u32) -> Result<PrivateKey, AleoError> {
+        Ok(PrivateKey::from(Self::derive_child_native(self.master_key()?, index)?))
+    }
+
+    /// Derive the private keys of a range of consecutive child accounts
+    ///
+    /// @param {number} start Index of the first child account
+    /// @param {number} count Number of child accounts to derive, at most 1000
+    /// @returns {Array} Array of private keys for the child accounts `start..start + count`
+    #[wasm_bindgen(js_name = deriveChildren)]
+    pub fn derive_children(&self, start: u32, count: u32) -> Result<Array, AleoError> {
+        if count > MAX_DERIVED_CHILDREN {
+            return Err(AleoError::invalid_input(format!(
+                "At most {MAX_DERIVED_CHILDREN} child accounts can be derived at once"
+            )));
+        }
+        if count == 0 {
+            return Ok(Array::new());
+        }
+        let last =
+            start.checked_add(count - 1).ok_or_else(|| AleoError::invalid_input("The child index range overflowed"))?;
+        let master = self.master_key()?;
+        let children = Array::new_with_length(count);
+        for (position, index) in (start..=last).enumerate() {
+            children.set(position as u32, PrivateKey::from(Self::derive_child_native(master, index)?).into());
+        }
+        Ok(children)
+    }
+}
+
+impl MasterSeed {
+    // Derive the master key from the master seed. The seed is packed into field elements without
+    // reduction and its length is hashed alongside them, so distinct seeds give distinct master keys
+    fn master_key(&self) -> Result<FieldNative, AleoError> {
+        let mut input =
+            vec![FieldNative::new_domain_separator(HD_MASTER_KEY_DOMAIN), FieldNative::from_u32(self.0.len() as u32)];
+        input.extend(
+            self.0
+                .chunks(SEED_BYTES_PER_FIELD)
+                .map(|chunk| FieldNative::new(<CurrentNetwork as Environment>::Field::from_bytes_le_mod_order(chunk))),
+        );
+        CurrentNetwork::hash_psd2(&input).map_err(AleoError::invalid_input)
+    }
+
+    // Derive the private key of a child account from the master key
+    fn derive_child_native(master: FieldNative, index: u32) -> Result<PrivateKeyNative, AleoError> {
+        let domain = FieldNative::new_domain_separator(HD_CHILD_KEY_DOMAIN);
+        let child = CurrentNetwork::hash_psd2(&[domain, master, FieldNative::from_u32(index)])
+            .map_err(AleoError::invalid_input)?;
+        let seed: [u8; 32] = child
+            .to_bytes_le()
+            .map_err(AleoError::invalid_input)?
+            .try_into()
+            .map_err(|_| AleoError::invalid_input("Child seed derivation failed"))?;
+        PrivateKey::seed_to_private_key(&seed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use wasm_bindgen_test::*;
+
+    const ITERATIONS: u32 = 100;
+
+    const MNEMONIC: &str =
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    #[wasm_bindgen_test]
+    pub fn test_derive_child_is_deterministic() {
+        let master_seed = MasterSeed::new();
+        let recovered = MasterSeed::from_bytes(&master_seed.to_bytes()).unwrap();
+
+        for index in 0..ITERATIONS {
+            // Ensure the child is recoverable from a backup of the seed.
+            let expected = master_seed.derive_child(index).unwrap();
+            assert_eq!(expected, recovered.derive_child(index).unwrap());
+
+            // Ensure neighbouring children are distinct.
+            assert_ne!(expected, master_seed.derive_child(index + 1).unwrap());
+        }
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_different_seeds_derive_different_children() {
+        let master_seed_1 = MasterSeed::new();
+        let master_seed_2 = MasterSeed::new();
+        assert_ne!(master_seed_1.derive_child(0).unwrap(), master_seed_2.derive_child(0).unwrap());
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_derive_children() {
+        let master_seed = MasterSeed::new();
+        let children = master_seed.derive_children(5, 3).unwrap();
+        assert_eq!(children.length(), 3);
+        assert_eq!(master_seed.derive_children(5, 0).unwrap().length(), 0);
+
+        // Ensure the last index can be derived but not exceeded.
+        let last = master_seed.derive_children(u32::MAX, 1).unwrap();
+        assert_eq!(last.length(), 1);
+        assert!(master_seed.derive_children(u32::MAX, 2).is_err());
+        assert!(master_seed.derive_children(u32::MAX - 1, 2).is_ok());
+
+        // Ensure the number of children derived at once is bounded.
+        assert!(master_seed.derive_children(0, MAX_DERIVED_CHILDREN + 1).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_seed_length_is_bound() {
+        let seed = [7u8; 16];
+        let mut extended = seed.to_vec();
+        extended.push(0);
+        let master_seed = MasterSeed::from_bytes(&seed).unwrap();
+        let extended = MasterSeed::from_bytes(&extended).unwrap();
+        assert_ne!(master_seed.derive_child(0).unwrap(), extended.derive_child(0).unwrap());
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_from_mnemonic() {
+        let master_seed = MasterSeed::from_mnemonic(MNEMONIC, None).unwrap();
+        assert_eq!(master_seed, MasterSeed::from_mnemonic(MNEMONIC, None).unwrap());
+        assert_ne!(master_seed, MasterSeed::from_mnemonic(MNEMONIC, Some("passphrase".to_string())).unwrap());
+        // Ensure the children are domain separated from the account the phrase itself recovers.
+        assert_ne!(master_seed.derive_child(0).unwrap(), PrivateKey::from_mnemonic(MNEMONIC, None).unwrap());
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_invalid_seed_length() {
+        assert!(MasterSeed::from_bytes(&[0u8; 15]).is_err());
+        assert!(MasterSeed::from_bytes(&[0u8; 65]).is_err());
+        assert!(MasterSeed::from_bytes(&[0u8; 16]).is_ok());
+        assert!(MasterSeed::from_bytes(&[0u8; 64]).is_ok());
+    }
+}
@@ -17,9 +17,24 @@
 pub mod address;
 pub use address::*;
 
+pub mod address_ciphertext;
+pub use address_ciphertext::*;
+
+pub mod compute_key;
+pub use compute_key::*;
+
 pub mod encryptor;
 pub use encryptor::*;
 
+pub mod graph_key;
+pub use graph_key::*;
+
+pub mod keystore;
+pub use keystore::*;
+
+pub mod master_seed;
+pub use master_seed::*;
+
 pub mod private_key;
 pub use private_key::*;
 
@@ -29,5 +44,11 @@ pub use private_key_ciphertext::*;
 pub mod signature;
 pub use signature::*;
 
+pub mod symmetric_ciphertext;
+pub use symmetric_ciphertext::*;
+
+pub mod vanity;
+pub use vanity::*;
+
 pub mod view_key;
 pub use view_key::*;
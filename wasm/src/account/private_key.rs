@@ -14,13 +14,28 @@
 // You should have received a copy of the GNU General Public License
 // along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::account::{Address, Encryptor, PrivateKeyCiphertext, Signature, ViewKey};
-
-use crate::types::native::{CurrentNetwork, Environment, FromBytes, PrimeField, PrivateKeyNative, ToBytes};
+use crate::{
+    account::{Address, ComputeKey, Encryptor, KdfParams, Keystore, PrivateKeyCiphertext, Signature, ViewKey},
+    AleoError,
+};
+
+use crate::types::native::{
+    CurrentNetwork,
+    Environment,
+    FieldNative,
+    FromBytes,
+    PrimeField,
+    PrivateKeyNative,
+    ToBytes,
+};
+use bip39::{Language, Mnemonic};
 use core::{convert::TryInto, fmt, ops::Deref, str::FromStr};
 use rand::{rngs::StdRng, SeedableRng};
 use wasm_bindgen::prelude::*;
 
+/// Salt used to stretch a mnemonic passphrase into the field element added to the private key seed
+const MNEMONIC_PASSPHRASE_DOMAIN: &str = "AleoMnemonicPassphrase";
+
 /// Private key of an Aleo account
 #[wasm_bindgen]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -41,21 +56,55 @@ impl PrivateKey {
     ///
     /// @param {Uint8Array} seed Unchecked 32 byte long Uint8Array acting as the seed for the private key
     /// @returns {PrivateKey}
-    pub fn from_seed_unchecked(seed: &[u8]) -> PrivateKey {
+    pub fn from_seed_unchecked(seed: &[u8]) -> Result<PrivateKey, AleoError> {
         // Cast into a fixed-size byte array. Note: This is a **hard** requirement for security.
-        let seed: [u8; 32] = seed.try_into().unwrap();
-        // Recover the field element deterministically.
-        let field = <CurrentNetwork as Environment>::Field::from_bytes_le_mod_order(&seed);
-        // Cast and recover the private key from the seed.
-        Self(PrivateKeyNative::try_from(FromBytes::read_le(&*field.to_bytes_le().unwrap()).unwrap()).unwrap())
+        let seed: [u8; 32] = seed
+            .try_into()
+            .map_err(|_| AleoError::parse(format!("Private key seeds must be 32 bytes, found {} bytes", seed.len())))?;
+        Ok(Self(Self::seed_to_private_key(&seed)?))
+    }
+
+    /// Get a private key from a BIP-39 mnemonic phrase of 12 or 24 English words
+    ///
+    /// The entropy encoded by the phrase is read as a little-endian integer and used as the private
+    /// key seed. 24 word phrases whose entropy is not below the field modulus are rejected, so every
+    /// accepted phrase maps to a distinct key and is returned unchanged by `toMnemonic`. 12 word
+    /// phrases are accepted, but `toMnemonic` exports their key as the equivalent 24 word phrase.
+    /// If a passphrase is provided, its bytes are stretched with scrypt salted by
+    /// "AleoMnemonicPassphrase" and the result is added to the seed, so the same phrase recovers a
+    /// different account for every passphrase
+    ///
+    /// @param {string} mnemonic Space separated mnemonic phrase of 12 or 24 words
+    /// @param {string | undefined} passphrase Optional passphrase protecting the mnemonic
+    /// @returns {PrivateKey}
+    #[wasm_bindgen(js_name = fromMnemonic)]
+    pub fn from_mnemonic(mnemonic: &str, passphrase: Option<String>) -> Result<PrivateKey, AleoError> {
+        let seed = Self::mnemonic_to_seed(mnemonic, passphrase.as_deref())?;
+        Ok(Self(PrivateKeyNative::try_from(seed).map_err(AleoError::invalid_input)?))
+    }
+
+    /// Get a 24 word BIP-39 mnemonic phrase which recovers this private key via `fromMnemonic`.
+    /// This function should be used very carefully as the phrase is equivalent to the private key
+    ///
+    /// @param {string | undefined} passphrase Optional passphrase which will be required to recover the key
+    /// @returns {string} Space separated mnemonic phrase of 24 words
+    #[wasm_bindgen(js_name = toMnemonic)]
+    pub fn to_mnemonic(&self, passphrase: Option<String>) -> Result<String, AleoError> {
+        let entropy = match Self::passphrase_mask(passphrase.as_deref())? {
+            Some(mask) => self.0.seed() - mask,
+            None => self.0.seed(),
+        };
+        let entropy = entropy.to_bytes_le().map_err(AleoError::invalid_input)?;
+        let mnemonic = Mnemonic::from_entropy_in(Language::English, &entropy).map_err(AleoError::invalid_input)?;
+        Ok(mnemonic.to_string())
     }
 
     /// Get a private key from a string representation of a private key
     ///
     /// @param {string} seed String representation of a private key
     /// @returns {PrivateKey}
-    pub fn from_string(private_key: &str) -> Result<PrivateKey, String> {
-        Self::from_str(private_key).map_err(|_| "Invalid private key".to_string())
+    pub fn from_string(private_key: &str) -> Result<PrivateKey, AleoError> {
+        Self::from_str(private_key).map_err(|_| AleoError::parse("Invalid private key"))
     }
 
     /// Get a string representation of the private key. This function should be used very carefully
@@ -74,6 +123,13 @@ impl PrivateKey {
         ViewKey::from_private_key(self)
     }
 
+    /// Get the compute key corresponding to the private key
+    ///
+    /// @returns {ComputeKey}
+    pub fn to_compute_key(&self) -> Result<ComputeKey, AleoError> {
+        ComputeKey::from_private_key(self)
+    }
+
     /// Get the address corresponding to the private key
     ///
     /// @returns {Address}
@@ -89,17 +145,26 @@ impl PrivateKey {
         Signature::sign(self, message)
     }
 
+    /// Sign an Aleo plaintext value with the private key so that it can be verified by an Aleo
+    /// program using the `sign.verify` instruction
+    ///
+    /// @param {string} message String representation of the plaintext value to sign
+    /// @returns {Signature} Signature generated by signing the value with the private key
+    #[wasm_bindgen(js_name = signValue)]
+    pub fn sign_value(&self, message: &str) -> Result<Signature, AleoError> {
+        Signature::sign_value(self, message)
+    }
+
     /// Get a new randomly generated private key ciphertext using a secret. The secret is sensitive
     /// and will be needed to decrypt the private key later, so it should be stored securely
     ///
     /// @param {string} secret Secret used to encrypt the private key
     /// @returns {PrivateKeyCiphertext} Ciphertext representation of the private key
     #[wasm_bindgen(js_name = newEncrypted)]
-    pub fn new_encrypted(secret: &str) -> Result<PrivateKeyCiphertext, String> {
+    pub fn new_encrypted(secret: &str) -> Result<PrivateKeyCiphertext, AleoError> {
         let key = Self::new();
-        let ciphertext =
-            Encryptor::encrypt_private_key_with_secret(&key, secret).map_err(|_| "Encryption failed".to_string())?;
-        Ok(PrivateKeyCiphertext::from(ciphertext))
+        Encryptor::encrypt_private_key_with_secret(&key, secret)
+            .map_err(|_| AleoError::invalid_input("Encryption failed"))
     }
 
     /// Encrypt an existing private key with a secret. The secret is sensitive and will be needed to
@@ -108,10 +173,9 @@ impl PrivateKey {
     /// @param {string} secret Secret used to encrypt the private key
     /// @returns {PrivateKeyCiphertext} Ciphertext representation of the private key
     #[wasm_bindgen(js_name = toCiphertext)]
-    pub fn to_ciphertext(&self, secret: &str) -> Result<PrivateKeyCiphertext, String> {
-        let ciphertext =
-            Encryptor::encrypt_private_key_with_secret(self, secret).map_err(|_| "Encryption failed".to_string())?;
-        Ok(PrivateKeyCiphertext::from(ciphertext))
+    pub fn to_ciphertext(&self, secret: &str) -> Result<PrivateKeyCiphertext, AleoError> {
+        Encryptor::encrypt_private_key_with_secret(self, secret)
+            .map_err(|_| AleoError::invalid_input("Encryption failed"))
     }
 
     /// Get private key from a private key ciphertext and secret originally used to encrypt it
@@ -120,11 +184,82 @@ impl PrivateKey {
     /// @param {string} secret Secret originally used to encrypt the private key
     /// @returns {PrivateKey} Private key
     #[wasm_bindgen(js_name = fromPrivateKeyCiphertext)]
-    pub fn from_private_key_ciphertext(ciphertext: &PrivateKeyCiphertext, secret: &str) -> Result<PrivateKey, String> {
+    pub fn from_private_key_ciphertext(
+        ciphertext: &PrivateKeyCiphertext,
+        secret: &str,
+    ) -> Result<PrivateKey, AleoError> {
         let private_key = Encryptor::decrypt_private_key_with_secret(ciphertext, secret)
-            .map_err(|_| "Decryption failed".to_string())?;
+            .map_err(|_| AleoError::invalid_input("Decryption failed"))?;
         Ok(Self::from(private_key))
     }
+
+    /// Encrypt the private key into a JSON keystore using a password. The keystore holds the
+    /// address of the account in the clear so it can be listed without being decrypted
+    ///
+    /// @param {string} password Password used to encrypt the private key
+    /// @returns {string} JSON keystore
+    #[wasm_bindgen(js_name = toKeystore)]
+    pub fn to_keystore(&self, password: &str) -> Result<String, AleoError> {
+        Ok(Keystore::encrypt(self, password)?.to_string())
+    }
+
+    /// Get a private key from a JSON keystore and the password originally used to encrypt it
+    ///
+    /// @param {string} keystore JSON keystore
+    /// @param {string} password Password used to encrypt the private key
+    /// @returns {PrivateKey}
+    #[wasm_bindgen(js_name = fromKeystore)]
+    pub fn from_keystore(keystore: &str, password: &str) -> Result<PrivateKey, AleoError> {
+        Keystore::from_string(keystore)?.decrypt(password)
+    }
+}
+
+impl PrivateKey {
+    /// Recover a private key from a 32 byte seed by reducing it into a field element
+    pub(crate) fn seed_to_private_key(seed: &[u8; 32]) -> Result<PrivateKeyNative, AleoError> {
+        // Recover the field element deterministically.
+        let field = <CurrentNetwork as Environment>::Field::from_bytes_le_mod_order(seed);
+        // Cast and recover the private key from the seed.
+        let field: FieldNative = FromBytes::read_le(&*field.to_bytes_le().map_err(AleoError::invalid_input)?)
+            .map_err(AleoError::invalid_input)?;
+        PrivateKeyNative::try_from(field).map_err(AleoError::invalid_input)
+    }
+
+    /// Recover the private key seed encoded by a mnemonic phrase and an optional passphrase
+    pub(crate) fn mnemonic_to_seed(mnemonic: &str, passphrase: Option<&str>) -> Result<FieldNative, AleoError> {
+        let words = mnemonic.split_whitespace().collect::<Vec<&str>>().join(" ").to_lowercase();
+        let mnemonic = Mnemonic::parse_in_normalized(Language::English, &words)
+            .map_err(|e| AleoError::parse(format!("Invalid mnemonic: {e}")))?;
+        if !matches!(mnemonic.word_count(), 12 | 24) {
+            return Err(AleoError::invalid_input("Mnemonic phrases must contain 12 or 24 words"));
+        }
+
+        // Recover the seed from the mnemonic entropy, rejecting entropy that is not below the field
+        // modulus as it would be reduced onto the seed of another phrase, then apply the passphrase.
+        let entropy = mnemonic.to_entropy();
+        let field = <CurrentNetwork as Environment>::Field::from_bytes_le_mod_order(&entropy);
+        if field.to_bytes_le().map_err(AleoError::invalid_input)?[..entropy.len()] != entropy[..] {
+            return Err(AleoError::invalid_input("The mnemonic entropy is not below the field modulus"));
+        }
+        let entropy = FieldNative::new(field);
+        match Self::passphrase_mask(passphrase)? {
+            Some(mask) => Ok(entropy + mask),
+            None => Ok(entropy),
+        }
+    }
+
+    // Derive the field element that a mnemonic passphrase adds to the private key seed
+    fn passphrase_mask(passphrase: Option<&str>) -> Result<Option<FieldNative>, AleoError> {
+        match passphrase {
+            Some(passphrase) if !passphrase.is_empty() => {
+                // Scrypt reads the raw bytes of the passphrase, so distinct passphrases give distinct
+                // masks, and stretching it slows down brute forcing the passphrase of a leaked phrase
+                let kdf = KdfParams::with_salt(MNEMONIC_PASSPHRASE_DOMAIN.as_bytes());
+                Ok(Some(Encryptor::derive_key(passphrase, &kdf)?))
+            }
+            _ => Ok(None),
+        }
+    }
 }
 
 impl From<PrivateKeyNative> for PrivateKey {
@@ -169,6 +304,7 @@ impl Deref for PrivateKey {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::ErrorCode;
 
     use rand::Rng;
     use wasm_bindgen_test::*;
@@ -211,9 +347,83 @@ mod tests {
             let seed: [u8; 32] = StdRng::from_entropy().gen();
 
             // Ensure the private key is deterministically recoverable.
-            let expected = PrivateKey::from_seed_unchecked(&seed);
-            assert_eq!(expected, PrivateKey::from_seed_unchecked(&seed));
+            let expected = PrivateKey::from_seed_unchecked(&seed).unwrap();
+            assert_eq!(expected, PrivateKey::from_seed_unchecked(&seed).unwrap());
         }
+
+        // Ensure seeds of the wrong length are rejected
+        let error = PrivateKey::from_seed_unchecked(&[0u8; 31]).unwrap_err();
+        assert_eq!(error.code(), ErrorCode::Parse);
+        assert!(PrivateKey::from_seed_unchecked(&[0u8; 33]).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_mnemonic_round_trip() {
+        for _ in 0..ITERATIONS {
+            // Sample a new private key.
+            let expected = PrivateKey::new();
+
+            // Check the private key and the phrase survive a round trip.
+            let mnemonic = expected.to_mnemonic(None).unwrap();
+            assert_eq!(mnemonic.split(' ').count(), 24);
+            let recovered = PrivateKey::from_mnemonic(&mnemonic, None).unwrap();
+            assert_eq!(expected, recovered);
+            assert_eq!(mnemonic, recovered.to_mnemonic(None).unwrap());
+        }
+
+        // Check the private key is only recoverable with the passphrase it was exported with.
+        let expected = PrivateKey::new();
+        let mnemonic = expected.to_mnemonic(Some("passphrase".to_string())).unwrap();
+        assert_eq!(expected, PrivateKey::from_mnemonic(&mnemonic, Some("passphrase".to_string())).unwrap());
+        assert_ne!(expected, PrivateKey::from_mnemonic(&mnemonic, Some("wrong".to_string())).unwrap());
+        assert_ne!(expected, PrivateKey::from_mnemonic(&mnemonic, None).unwrap());
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_mnemonic_distinct_passphrases() {
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+        // Ensure passphrases which only differ by trailing zero bytes or beyond 31 bytes give distinct keys.
+        let long = "a".repeat(40);
+        let passphrases = ["abc".to_string(), "abc\0".to_string(), format!("{long}b"), format!("{long}c")];
+        let keys = passphrases
+            .iter()
+            .map(|passphrase| PrivateKey::from_mnemonic(mnemonic, Some(passphrase.clone())).unwrap())
+            .collect::<Vec<_>>();
+        for (i, key) in keys.iter().enumerate() {
+            assert!(keys[i + 1..].iter().all(|other| other != key));
+        }
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_mnemonic_twelve_words() {
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+        // Ensure 12 word phrases are deterministic and insensitive to case and whitespace.
+        let expected = PrivateKey::from_mnemonic(mnemonic, None).unwrap();
+        assert_eq!(expected, PrivateKey::from_mnemonic(&format!("  {}  ", mnemonic.to_uppercase()), None).unwrap());
+        assert_ne!(expected, PrivateKey::from_mnemonic(mnemonic, Some("passphrase".to_string())).unwrap());
+
+        // Ensure an empty passphrase is the same as no passphrase.
+        assert_eq!(expected, PrivateKey::from_mnemonic(mnemonic, Some("".to_string())).unwrap());
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_mnemonic_invalid() {
+        // Bad checksum.
+        let bad_checksum =
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon";
+        assert!(PrivateKey::from_mnemonic(bad_checksum, None).is_err());
+        // Unknown word.
+        assert!(
+            PrivateKey::from_mnemonic("aleo aleo aleo aleo aleo aleo aleo aleo aleo aleo aleo aleo", None).is_err()
+        );
+        // Unsupported word count.
+        let fifteen_words = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon address";
+        assert!(PrivateKey::from_mnemonic(fifteen_words, None).is_err());
+        // Entropy which is not below the field modulus.
+        let above_modulus = format!("{} vote", ["zoo"; 23].join(" "));
+        assert!(PrivateKey::from_mnemonic(&above_modulus, None).is_err());
     }
 
     #[wasm_bindgen_test]
@@ -14,16 +14,35 @@
 // You should have received a copy of the GNU General Public License
 // along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::account::{Encryptor, PrivateKey};
+use crate::{
+    account::{Encryptor, KdfParams, PrivateKey},
+    AleoError,
+};
 
 use crate::types::native::CiphertextNative;
+use serde::{Deserialize, Serialize};
 use std::{ops::Deref, str::FromStr};
 use wasm_bindgen::prelude::*;
 
+/// Version of private key ciphertexts whose secret is stretched with the scrypt key derivation function
+const KDF_CIPHERTEXT_VERSION: u8 = 2;
+
 /// Private Key in ciphertext form
 #[wasm_bindgen]
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct PrivateKeyCiphertext(CiphertextNative);
+pub struct PrivateKeyCiphertext {
+    ciphertext: CiphertextNative,
+    kdf: Option<KdfParams>,
+}
+
+/// Versioned envelope used to serialize private key ciphertexts along with their key derivation parameters
+#[derive(Deserialize, Serialize)]
+struct PrivateKeyCiphertextEnvelope {
+    version: u8,
+    kdf: String,
+    kdfparams: KdfParams,
+    ciphertext: String,
+}
 
 #[wasm_bindgen]
 impl PrivateKeyCiphertext {
@@ -34,10 +53,9 @@ impl PrivateKeyCiphertext {
     /// @param {string} secret Secret to encrypt the private key with
     /// @returns {PrivateKeyCiphertext} Private key ciphertext
     #[wasm_bindgen(js_name = encryptPrivateKey)]
-    pub fn encrypt_private_key(private_key: &PrivateKey, secret: &str) -> Result<PrivateKeyCiphertext, String> {
-        let ciphertext = Encryptor::encrypt_private_key_with_secret(private_key, secret)
-            .map_err(|_| "Encryption failed".to_string())?;
-        Ok(Self::from(ciphertext))
+    pub fn encrypt_private_key(private_key: &PrivateKey, secret: &str) -> Result<PrivateKeyCiphertext, AleoError> {
+        Encryptor::encrypt_private_key_with_secret(private_key, secret)
+            .map_err(|_| AleoError::invalid_input("Encryption failed"))
     }
 
     /// Decrypts a private ciphertext using a secret string. This must be the same secret used to
@@ -46,19 +64,56 @@ impl PrivateKeyCiphertext {
     /// @param {string} secret Secret used to encrypt the private key
     /// @returns {PrivateKey} Private key
     #[wasm_bindgen(js_name = decryptToPrivateKey)]
-    pub fn decrypt_to_private_key(&self, secret: &str) -> Result<PrivateKey, String> {
-        let private_key = Encryptor::decrypt_private_key_with_secret(&self.0, secret)
-            .map_err(|_| "Decryption failed - ciphertext was not a private key")?;
+    pub fn decrypt_to_private_key(&self, secret: &str) -> Result<PrivateKey, AleoError> {
+        let private_key = Encryptor::decrypt_private_key_with_secret(self, secret)
+            .map_err(|_| AleoError::invalid_input("Decryption failed - ciphertext was not a private key"))?;
         Ok(PrivateKey::from(private_key))
     }
 
+    /// Returns the version of the ciphertext. Version 1 ciphertexts use the secret directly as the
+    /// encryption key, version 2 ciphertexts stretch the secret with the scrypt key derivation function
+    ///
+    /// @returns {number} Ciphertext version
+    pub fn version(&self) -> u8 {
+        match self.kdf {
+            Some(_) => KDF_CIPHERTEXT_VERSION,
+            None => 1,
+        }
+    }
+
+    /// Returns true if the ciphertext was created without a key derivation function and should be upgraded
+    ///
+    /// @returns {boolean}
+    #[wasm_bindgen(js_name = isLegacy)]
+    pub fn is_legacy(&self) -> bool {
+        self.kdf.is_none()
+    }
+
+    /// Re-encrypt the private key under the latest ciphertext version using the same secret
+    ///
+    /// @param {string} secret Secret used to encrypt the private key
+    /// @returns {PrivateKeyCiphertext} Private key ciphertext of the latest version
+    pub fn upgrade(&self, secret: &str) -> Result<PrivateKeyCiphertext, AleoError> {
+        let private_key = self.decrypt_to_private_key(secret)?;
+        Self::encrypt_private_key(&private_key, secret)
+    }
+
     /// Returns the ciphertext string
     ///
     /// @returns {string} Ciphertext string
     #[allow(clippy::inherent_to_string)]
     #[wasm_bindgen(js_name = toString)]
     pub fn to_string(&self) -> String {
-        self.0.to_string()
+        match &self.kdf {
+            Some(kdf) => serde_json::to_string(&PrivateKeyCiphertextEnvelope {
+                version: KDF_CIPHERTEXT_VERSION,
+                kdf: "scrypt".to_string(),
+                kdfparams: kdf.clone(),
+                ciphertext: self.ciphertext.to_string(),
+            })
+            .unwrap(),
+            None => self.ciphertext.to_string(),
+        }
     }
 
     /// Creates a PrivateKeyCiphertext from a string
@@ -66,14 +121,25 @@ impl PrivateKeyCiphertext {
     /// @param {string} ciphertext Ciphertext string
     /// @returns {PrivateKeyCiphertext} Private key ciphertext
     #[wasm_bindgen(js_name = fromString)]
-    pub fn from_string(ciphertext: String) -> Result<PrivateKeyCiphertext, String> {
-        Self::try_from(ciphertext).map_err(|_| "Invalid ciphertext".to_string())
+    pub fn from_string(ciphertext: String) -> Result<PrivateKeyCiphertext, AleoError> {
+        Self::try_from(ciphertext).map_err(AleoError::parse)
+    }
+}
+
+impl PrivateKeyCiphertext {
+    pub(crate) fn new(ciphertext: CiphertextNative, kdf: Option<KdfParams>) -> Self {
+        Self { ciphertext, kdf }
+    }
+
+    /// Returns the key derivation parameters if the ciphertext uses a key derivation function
+    pub fn kdf(&self) -> Option<&KdfParams> {
+        self.kdf.as_ref()
     }
 }
 
 impl From<CiphertextNative> for PrivateKeyCiphertext {
     fn from(ciphertext: CiphertextNative) -> Self {
-        Self(ciphertext)
+        Self::new(ciphertext, None)
     }
 }
 
@@ -81,7 +147,19 @@ impl TryFrom<String> for PrivateKeyCiphertext {
     type Error = String;
 
     fn try_from(ciphertext: String) -> Result<Self, Self::Error> {
-        Ok(Self(CiphertextNative::from_str(&ciphertext).map_err(|_| "Invalid ciphertext".to_string())?))
+        if !ciphertext.trim_start().starts_with('{') {
+            return Ok(Self::from(
+                CiphertextNative::from_str(&ciphertext).map_err(|_| "Invalid ciphertext".to_string())?,
+            ));
+        }
+        let envelope: PrivateKeyCiphertextEnvelope =
+            serde_json::from_str(&ciphertext).map_err(|_| "Invalid ciphertext".to_string())?;
+        if envelope.version != KDF_CIPHERTEXT_VERSION || envelope.kdf != "scrypt" {
+            return Err(format!("Unsupported ciphertext version {} with kdf {}", envelope.version, envelope.kdf));
+        }
+        let ciphertext =
+            CiphertextNative::from_str(&envelope.ciphertext).map_err(|_| "Invalid ciphertext".to_string())?;
+        Ok(Self::new(ciphertext, Some(envelope.kdfparams)))
     }
 }
 
@@ -89,7 +167,7 @@ impl Deref for PrivateKeyCiphertext {
     type Target = CiphertextNative;
 
     fn deref(&self) -> &Self::Target {
-        &self.0
+        &self.ciphertext
     }
 }
 
@@ -99,6 +177,8 @@ mod tests {
 
     use wasm_bindgen_test::wasm_bindgen_test;
 
+    const LEGACY_CIPHERTEXT: &str = "ciphertext1qvqg7rgvam3xdcu55pwu6sl8rxwefxaj5gwthk0yzln6jv5fastzup0qn0qftqlqq7jcckyx03fzv9kke0z9puwd7cl7jzyhxfy2f2juplz39dkqs6p24urhxymhv364qm3z8mvyklv5gr52n4fxr2z59jgqytyddj8";
+
     #[wasm_bindgen_test]
     fn test_private_key_ciphertext_to_and_from_string() {
         let private_key = PrivateKey::new();
@@ -113,8 +193,7 @@ mod tests {
     fn test_private_key_from_string_decryption_edge_cases() {
         let private_key =
             PrivateKey::from_string("APrivateKey1zkpAYS46Dq4rnt9wdohyWMwdmjmTeMJKPZdp5AhvjXZDsVG").unwrap();
-        let ciphertext = "ciphertext1qvqg7rgvam3xdcu55pwu6sl8rxwefxaj5gwthk0yzln6jv5fastzup0qn0qftqlqq7jcckyx03fzv9kke0z9puwd7cl7jzyhxfy2f2juplz39dkqs6p24urhxymhv364qm3z8mvyklv5gr52n4fxr2z59jgqytyddj8";
-        let private_key_ciphertext = PrivateKeyCiphertext::from_string(ciphertext.to_string()).unwrap();
+        let private_key_ciphertext = PrivateKeyCiphertext::from_string(LEGACY_CIPHERTEXT.to_string()).unwrap();
         let decrypted_private_key = private_key_ciphertext.decrypt_to_private_key("mypassword").unwrap();
 
         // Assert that the private key is the same as the original for a valid ciphertext and secret
@@ -126,6 +205,32 @@ mod tests {
         assert!(PrivateKeyCiphertext::from_string(bad_ciphertext.to_string()).is_err());
     }
 
+    #[wasm_bindgen_test]
+    fn test_private_key_ciphertext_versions() {
+        let private_key =
+            PrivateKey::from_string("APrivateKey1zkpAYS46Dq4rnt9wdohyWMwdmjmTeMJKPZdp5AhvjXZDsVG").unwrap();
+        let legacy = PrivateKeyCiphertext::from_string(LEGACY_CIPHERTEXT.to_string()).unwrap();
+        assert!(legacy.is_legacy());
+        assert_eq!(legacy.version(), 1);
+        assert_eq!(legacy.to_string(), LEGACY_CIPHERTEXT);
+
+        // Assert that upgrading a legacy ciphertext tags it with the key derivation parameters
+        let upgraded = legacy.upgrade("mypassword").unwrap();
+        assert!(!upgraded.is_legacy());
+        assert_eq!(upgraded.version(), 2);
+        assert!(legacy.upgrade("badpassword").is_err());
+
+        // Assert that the upgraded ciphertext survives a string round trip and still decrypts to the same key
+        let recovered = PrivateKeyCiphertext::from_string(upgraded.to_string()).unwrap();
+        assert_eq!(upgraded, recovered);
+        assert_eq!(private_key, recovered.decrypt_to_private_key("mypassword").unwrap());
+        assert!(recovered.decrypt_to_private_key("badpassword").is_err());
+
+        // Assert that unknown versions are rejected
+        let unknown_version = upgraded.to_string().replace("\"version\":2", "\"version\":3");
+        assert!(PrivateKeyCiphertext::from_string(unknown_version).is_err());
+    }
+
     #[wasm_bindgen_test]
     fn test_private_key_ciphertext_encrypt_and_decrypt() {
         let private_key = PrivateKey::new();
@@ -14,9 +14,12 @@
 // You should have received a copy of the GNU General Public License
 // along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::account::{Address, PrivateKey};
+use crate::{
+    account::{Address, PrivateKey},
+    AleoError,
+};
 
-use crate::types::native::SignatureNative;
+use crate::types::native::{PlaintextNative, SignatureNative};
 use core::{fmt, ops::Deref, str::FromStr};
 use rand::{rngs::StdRng, SeedableRng};
 use wasm_bindgen::prelude::*;
@@ -45,12 +48,41 @@ impl Signature {
         self.0.verify_bytes(address, message)
     }
 
+    /// Sign an Aleo plaintext value (literal, struct or array) with a private key. The value is
+    /// encoded into field elements the same way as the `sign.verify` instruction encodes its message,
+    /// so the signature can be verified by an Aleo program
+    ///
+    /// @param {PrivateKey} private_key The private key to sign the value with
+    /// @param {string} message String representation of the plaintext value to sign
+    /// @returns {Signature} Signature of the value
+    #[wasm_bindgen(js_name = signValue)]
+    pub fn sign_value(private_key: &PrivateKey, message: &str) -> Result<Signature, AleoError> {
+        let message = PlaintextNative::from_str(message).map_err(AleoError::parse)?;
+        let fields = message.to_fields().map_err(AleoError::invalid_input)?;
+        let signature = SignatureNative::sign(private_key, &fields, &mut StdRng::from_entropy())
+            .map_err(AleoError::invalid_input)?;
+        Ok(Self(signature))
+    }
+
+    /// Verify a signature of an Aleo plaintext value with an address
+    ///
+    /// @param {Address} address The address to verify the signature with
+    /// @param {string} message String representation of the plaintext value to verify
+    /// @returns {boolean} True if the signature is valid, false otherwise
+    #[wasm_bindgen(js_name = verifyValue)]
+    pub fn verify_value(&self, address: &Address, message: &str) -> bool {
+        match PlaintextNative::from_str(message).and_then(|message| message.to_fields()) {
+            Ok(fields) => self.0.verify(address, &fields),
+            Err(_) => false,
+        }
+    }
+
     /// Get a signature from a string representation of a signature
     ///
     /// @param {string} signature String representation of a signature
     /// @returns {Signature} Signature
-    pub fn from_string(signature: &str) -> Self {
-        Self::from_str(signature).unwrap()
+    pub fn from_string(signature: &str) -> Result<Signature, AleoError> {
+        Self::from_str(signature).map_err(|_| AleoError::parse("Invalid signature"))
     }
 
     /// Get a string representation of a signature
@@ -66,7 +98,7 @@ impl FromStr for Signature {
     type Err = anyhow::Error;
 
     fn from_str(signature: &str) -> Result<Self, Self::Err> {
-        Ok(Self(SignatureNative::from_str(signature).unwrap()))
+        Ok(Self(SignatureNative::from_str(signature)?))
     }
 }
 
@@ -111,4 +143,50 @@ mod tests {
             assert!(!signature.verify(&private_key.to_address(), &bad_message));
         }
     }
+
+    #[wasm_bindgen_test]
+    pub fn test_sign_and_verify_value() {
+        let private_key = PrivateKey::new();
+        let address = private_key.to_address();
+        let voucher = "{ recipient: aleo1j7qxyunfldj2lp8hsvy7mw5k8zaqgjfyr72x2gh3x4ewgae8v5gscf5jh3, amount: 100u64, ids: [1u8, 2u8] }";
+
+        // Sign the value and check the signature is valid.
+        let signature = Signature::sign_value(&private_key, voucher).unwrap();
+        assert!(signature.verify_value(&address, voucher));
+        assert!(address.verify_value(voucher, &signature));
+
+        // Check the signature is valid over the field encoding used by `sign.verify`.
+        let fields = PlaintextNative::from_str(voucher).unwrap().to_fields().unwrap();
+        assert!(signature.0.verify(&address, &fields));
+
+        // Check the signature is invalid for a different value, a different signer or the byte encoding.
+        let other = "{ recipient: aleo1j7qxyunfldj2lp8hsvy7mw5k8zaqgjfyr72x2gh3x4ewgae8v5gscf5jh3, amount: 101u64, ids: [1u8, 2u8] }";
+        assert!(!signature.verify_value(&address, other));
+        assert!(!signature.verify_value(&PrivateKey::new().to_address(), voucher));
+        assert!(!signature.verify(&address, voucher.as_bytes()));
+
+        // Check invalid values are rejected.
+        assert!(Signature::sign_value(&private_key, "not a value").is_err());
+        assert!(!signature.verify_value(&address, "not a value"));
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_sign_and_verify_literal_value() {
+        let private_key = PrivateKey::new();
+        let signature = private_key.sign_value("42field").unwrap();
+        assert!(signature.verify_value(&private_key.to_address(), "42field"));
+        assert!(!signature.verify_value(&private_key.to_address(), "42u64"));
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_from_string() {
+        let private_key = PrivateKey::new();
+        let signature = Signature::sign(&private_key, b"message");
+        let recovered = Signature::from_string(&signature.to_string()).unwrap();
+        assert!(recovered.verify(&private_key.to_address(), b"message"));
+
+        // Check invalid signatures return a parse error instead of panicking.
+        let error = Signature::from_string("sign1invalid").unwrap_err();
+        assert_eq!(error.code(), crate::ErrorCode::Parse);
+    }
 }
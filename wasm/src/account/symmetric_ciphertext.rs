@@ -0,0 +1,174 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{account::KdfParams, AleoError};
+
+use crate::types::native::{CiphertextNative, FieldNative};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use wasm_bindgen::prelude::*;
+
+/// Version of the symmetric ciphertext format
+const SYMMETRIC_CIPHERTEXT_VERSION: u8 = 1;
+
+/// Self-describing ciphertext produced by the Encryptor. Alongside the encrypted data it records
+/// what kind of content was encrypted, the domain it was encrypted under, the nonce used to blind
+/// the key and, when a password was used, the parameters of the key derivation function
+#[wasm_bindgen]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SymmetricCiphertext {
+    content: String,
+    domain: String,
+    kdf: Option<KdfParams>,
+    nonce: FieldNative,
+    ciphertext: CiphertextNative,
+}
+
+/// Versioned envelope used to serialize symmetric ciphertexts
+#[derive(Deserialize, Serialize)]
+struct SymmetricCiphertextEnvelope {
+    version: u8,
+    content: String,
+    domain: String,
+    kdf: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    kdfparams: Option<KdfParams>,
+    nonce: String,
+    ciphertext: String,
+}
+
+#[wasm_bindgen]
+impl SymmetricCiphertext {
+    /// Returns the kind of content held by the ciphertext (plaintext, bytes or view_key)
+    ///
+    /// @returns {string} Content kind
+    pub fn content(&self) -> String {
+        self.content.clone()
+    }
+
+    /// Returns the domain the content was encrypted under
+    ///
+    /// @returns {string} Domain
+    pub fn domain(&self) -> String {
+        self.domain.clone()
+    }
+
+    /// Returns true if the ciphertext was encrypted with a password rather than a field key
+    ///
+    /// @returns {boolean}
+    #[wasm_bindgen(js_name = isPasswordProtected)]
+    pub fn is_password_protected(&self) -> bool {
+        self.kdf.is_some()
+    }
+
+    /// Returns the JSON string representation of the ciphertext
+    ///
+    /// @returns {string} Ciphertext string
+    #[allow(clippy::inherent_to_string)]
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_string(&self) -> String {
+        serde_json::to_string(&SymmetricCiphertextEnvelope {
+            version: SYMMETRIC_CIPHERTEXT_VERSION,
+            content: self.content.clone(),
+            domain: self.domain.clone(),
+            kdf: if self.kdf.is_some() { "scrypt".to_string() } else { "none".to_string() },
+            kdfparams: self.kdf.clone(),
+            nonce: self.nonce.to_string(),
+            ciphertext: self.ciphertext.to_string(),
+        })
+        .unwrap()
+    }
+
+    /// Creates a SymmetricCiphertext from its JSON string representation
+    ///
+    /// @param {string} ciphertext Ciphertext string
+    /// @returns {SymmetricCiphertext} Symmetric ciphertext
+    #[wasm_bindgen(js_name = fromString)]
+    pub fn from_string(ciphertext: &str) -> Result<SymmetricCiphertext, AleoError> {
+        let envelope: SymmetricCiphertextEnvelope =
+            serde_json::from_str(ciphertext).map_err(|_| AleoError::parse("Invalid ciphertext"))?;
+        if envelope.version != SYMMETRIC_CIPHERTEXT_VERSION {
+            return Err(AleoError::parse(format!("Unsupported ciphertext version {}", envelope.version)));
+        }
+        match (envelope.kdf.as_str(), &envelope.kdfparams) {
+            ("scrypt", Some(_)) | ("none", None) => {}
+            _ => return Err(AleoError::parse(format!("Unsupported kdf {}", envelope.kdf))),
+        }
+        let nonce = FieldNative::from_str(&envelope.nonce).map_err(|_| AleoError::parse("Invalid ciphertext nonce"))?;
+        let ciphertext =
+            CiphertextNative::from_str(&envelope.ciphertext).map_err(|_| AleoError::parse("Invalid ciphertext"))?;
+        Ok(Self::new(&envelope.content, &envelope.domain, envelope.kdfparams, nonce, ciphertext))
+    }
+}
+
+impl SymmetricCiphertext {
+    pub(crate) fn new(
+        content: &str,
+        domain: &str,
+        kdf: Option<KdfParams>,
+        nonce: FieldNative,
+        ciphertext: CiphertextNative,
+    ) -> Self {
+        Self { content: content.to_string(), domain: domain.to_string(), kdf, nonce, ciphertext }
+    }
+
+    /// Returns the key derivation parameters if the ciphertext was encrypted with a password
+    pub fn kdf(&self) -> Option<&KdfParams> {
+        self.kdf.as_ref()
+    }
+
+    /// Returns the nonce used to derive the blinding factor of the ciphertext
+    pub fn nonce(&self) -> FieldNative {
+        self.nonce
+    }
+
+    /// Returns the underlying ciphertext
+    pub fn ciphertext(&self) -> &CiphertextNative {
+        &self.ciphertext
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::account::Encryptor;
+
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    #[wasm_bindgen_test]
+    fn test_symmetric_ciphertext_to_and_from_string() {
+        let ciphertext = Encryptor::encrypt_plaintext("{ a: 1u8, b: true }", "mypassword", "my_app").unwrap();
+        let recovered = SymmetricCiphertext::from_string(&ciphertext.to_string()).unwrap();
+        assert_eq!(ciphertext, recovered);
+        assert_eq!(recovered.content(), "plaintext");
+        assert_eq!(recovered.domain(), "my_app");
+        assert!(recovered.is_password_protected());
+
+        let key = crate::types::Field::from_string("1234field").unwrap();
+        let ciphertext = Encryptor::encrypt_bytes_with_key(&[1, 2, 3], &key, "my_app").unwrap();
+        let recovered = SymmetricCiphertext::from_string(&ciphertext.to_string()).unwrap();
+        assert_eq!(ciphertext, recovered);
+        assert!(!recovered.is_password_protected());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_symmetric_ciphertext_invalid_strings() {
+        assert!(SymmetricCiphertext::from_string("garbage").is_err());
+        let ciphertext = Encryptor::encrypt_plaintext("1u8", "mypassword", "my_app").unwrap().to_string();
+        assert!(SymmetricCiphertext::from_string(&ciphertext.replace("\"version\":1", "\"version\":9")).is_err());
+        assert!(SymmetricCiphertext::from_string(&ciphertext.replace("\"scrypt\"", "\"none\"")).is_err());
+    }
+}
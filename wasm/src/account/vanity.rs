@@ -0,0 +1,208 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{account::PrivateKey, thread_pool, AleoError};
+
+use crate::types::native::{AddressNative, PrivateKeyNative};
+use js_sys::Function;
+use rayon::prelude::*;
+use wasm_bindgen::prelude::*;
+
+/// Human readable part of an Aleo address
+const ADDRESS_PREFIX: &str = "aleo1";
+/// Characters of the bech32m alphabet used to encode addresses
+const BECH32_CHARSET: &str = "qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+/// Maximum length of a prefix pattern. The final data characters of an address encode the most
+/// significant bits of the address's x-coordinate, which are not uniformly distributed
+const MAX_PREFIX_LENGTH: usize = 50;
+/// Maximum length of a suffix pattern. Suffixes are matched against the uniformly distributed
+/// checksum at the end of the address
+const MAX_SUFFIX_LENGTH: usize = 6;
+/// Number of private keys sampled between progress reports
+const BATCH_SIZE: u64 = 10_000;
+
+/// Prefix and suffix an address must match, excluding the `aleo1` human readable part
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct VanityPattern {
+    prefix: String,
+    suffix: String,
+}
+
+impl VanityPattern {
+    /// Validate a prefix and suffix against the characters an address can contain
+    pub(crate) fn new(prefix: &str, suffix: &str) -> Result<Self, AleoError> {
+        let prefix = prefix.to_lowercase();
+        let prefix = prefix.strip_prefix(ADDRESS_PREFIX).unwrap_or(&prefix).to_string();
+        let suffix = suffix.to_lowercase();
+        for (name, pattern) in [("prefix", &prefix), ("suffix", &suffix)] {
+            if let Some(invalid) = pattern.chars().find(|c| !BECH32_CHARSET.contains(*c)) {
+                return Err(AleoError::invalid_input(format!(
+                    "The {name} contains '{invalid}', addresses may only contain the characters {BECH32_CHARSET}"
+                )));
+            }
+        }
+        if prefix.len() > MAX_PREFIX_LENGTH {
+            return Err(AleoError::invalid_input(format!(
+                "The prefix may be at most {MAX_PREFIX_LENGTH} characters long"
+            )));
+        }
+        if suffix.len() > MAX_SUFFIX_LENGTH {
+            return Err(AleoError::invalid_input(format!(
+                "The suffix may be at most {MAX_SUFFIX_LENGTH} characters long"
+            )));
+        }
+        Ok(Self { prefix, suffix })
+    }
+
+    /// Expected number of private keys to sample before finding a matching address
+    pub(crate) fn difficulty(&self) -> f64 {
+        32f64.powi((self.prefix.len() + self.suffix.len()) as i32)
+    }
+
+    /// Returns true if the address matches the pattern
+    pub(crate) fn matches(&self, address: &str) -> bool {
+        match address.strip_prefix(ADDRESS_PREFIX) {
+            Some(data) => data.starts_with(&self.prefix) && data.ends_with(&self.suffix),
+            None => false,
+        }
+    }
+
+    /// Sample private keys in parallel until one with a matching address is found or the attempts run out
+    pub(crate) fn search(&self, attempts: u64) -> Option<PrivateKeyNative> {
+        (0..attempts).into_par_iter().find_map_any(|_| {
+            let private_key = PrivateKeyNative::new(&mut rand::thread_rng()).ok()?;
+            let address = AddressNative::try_from(&private_key).ok()?;
+            self.matches(&address.to_string()).then_some(private_key)
+        })
+    }
+}
+
+/// Generator of private keys whose addresses match a chosen prefix and suffix
+#[wasm_bindgen]
+pub struct VanityGenerator;
+
+#[wasm_bindgen]
+impl VanityGenerator {
+    /// Search for a private key whose address matches a prefix and suffix. The search runs in
+    /// parallel on the thread pool started by `initThreadPool`
+    ///
+    /// @param {string} prefix Characters the address must start with, optionally including `aleo1`
+    /// @param {string} suffix Characters the address must end with
+    /// @param {BigInt | undefined} max_attempts Maximum number of private keys to sample before giving up
+    /// @param {Function | undefined} progress Callback invoked with the number of attempts made so far and the
+    /// expected number of attempts after each batch of private keys is sampled
+    /// @returns {PrivateKey} Private key of an address matching the pattern
+    pub async fn generate(
+        prefix: &str,
+        suffix: &str,
+        max_attempts: Option<u64>,
+        progress: Option<Function>,
+    ) -> Result<PrivateKey, AleoError> {
+        let pattern = VanityPattern::new(prefix, suffix)?;
+        let difficulty = pattern.difficulty();
+        let mut attempts = 0u64;
+        loop {
+            let batch_size = match max_attempts {
+                Some(max_attempts) => max_attempts.saturating_sub(attempts).min(BATCH_SIZE),
+                None => BATCH_SIZE,
+            };
+            if batch_size == 0 {
+                return Err(AleoError::invalid_input(format!(
+                    "No address matching the pattern was found in {attempts} attempts"
+                )));
+            }
+
+            let batch_pattern = pattern.clone();
+            let private_key = thread_pool::spawn(move || batch_pattern.search(batch_size)).await;
+            attempts += batch_size;
+            if let Some(private_key) = private_key {
+                return Ok(PrivateKey::from(private_key));
+            }
+
+            if let Some(progress) = &progress {
+                progress
+                    .call2(&JsValue::NULL, &JsValue::from_f64(attempts as f64), &JsValue::from_f64(difficulty))
+                    .map_err(|_| AleoError::invalid_input("The progress callback failed"))?;
+            }
+        }
+    }
+
+    /// Estimate the number of private keys that need to be sampled to find an address matching a
+    /// prefix and suffix. Every additional character multiplies the difficulty by 32
+    ///
+    /// @param {string} prefix Characters the address must start with, optionally including `aleo1`
+    /// @param {string} suffix Characters the address must end with
+    /// @returns {number} Expected number of attempts
+    pub fn difficulty(prefix: &str, suffix: &str) -> Result<f64, AleoError> {
+        Ok(VanityPattern::new(prefix, suffix)?.difficulty())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn test_vanity_pattern_validation() {
+        assert!(VanityPattern::new("team", "").is_ok());
+        assert_eq!(VanityPattern::new("aleo1TEAM", "").unwrap(), VanityPattern::new("team", "").unwrap());
+
+        // Assert characters outside of the bech32 alphabet are rejected
+        assert!(VanityPattern::new("bob", "").is_err());
+        assert!(VanityPattern::new("", "10").is_err());
+
+        // Assert patterns longer than the uniformly distributed parts of an address are rejected
+        assert!(VanityPattern::new(&"q".repeat(MAX_PREFIX_LENGTH + 1), "").is_err());
+        assert!(VanityPattern::new("", &"q".repeat(MAX_SUFFIX_LENGTH + 1)).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_vanity_pattern_matches() {
+        let address = "aleo1j7qxyunfldj2lp8hsvy7mw5k8zaqgjfyr72x2gh3x4ewgae8v5gscf5jh3";
+        assert!(VanityPattern::new("j7q", "").unwrap().matches(address));
+        assert!(VanityPattern::new("", "5jh3").unwrap().matches(address));
+        assert!(VanityPattern::new("aleo1j7", "h3").unwrap().matches(address));
+        assert!(!VanityPattern::new("q7j", "").unwrap().matches(address));
+        assert!(!VanityPattern::new("j7", "3h").unwrap().matches(address));
+    }
+
+    #[wasm_bindgen_test]
+    fn test_vanity_difficulty() {
+        assert_eq!(VanityGenerator::difficulty("", "").unwrap(), 1.0);
+        assert_eq!(VanityGenerator::difficulty("aleo1qq", "").unwrap(), 1024.0);
+        assert_eq!(VanityGenerator::difficulty("q", "qq").unwrap(), 32768.0);
+        assert!(VanityGenerator::difficulty("bob", "").is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_vanity_search() {
+        let pattern = VanityPattern::new("a", "").unwrap();
+        let private_key = pattern.search(BATCH_SIZE).unwrap();
+        let address = AddressNative::try_from(&private_key).unwrap().to_string();
+        assert!(address.starts_with("aleo1a"));
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_vanity_generate() {
+        let private_key = VanityGenerator::generate("aleo1q", "", None, None).await.unwrap();
+        assert!(private_key.to_address().to_string().starts_with("aleo1q"));
+
+        // Assert the search gives up once the attempt limit is reached
+        assert!(VanityGenerator::generate("qqqqqqqq", "", Some(10), None).await.is_err());
+    }
+}
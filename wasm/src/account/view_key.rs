@@ -15,7 +15,7 @@
 // along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
 
 use super::{Address, PrivateKey};
-use crate::record::RecordCiphertext;
+use crate::{record::RecordCiphertext, types::Field, AleoError};
 
 use crate::types::native::ViewKeyNative;
 use core::{convert::TryFrom, fmt, ops::Deref, str::FromStr};
@@ -39,8 +39,8 @@ impl ViewKey {
     ///
     /// @param {string} view_key String representation of a view key
     /// @returns {ViewKey} View key
-    pub fn from_string(view_key: &str) -> Self {
-        Self::from_str(view_key).unwrap()
+    pub fn from_string(view_key: &str) -> Result<ViewKey, AleoError> {
+        Self::from_str(view_key).map_err(|_| AleoError::parse("Invalid view key"))
     }
 
     /// Get a string representation of a view key
@@ -58,16 +58,29 @@ impl ViewKey {
         Address::from_view_key(self)
     }
 
+    /// Derive the Diffie-Hellman secret shared between this view key and the address of a counterparty.
+    /// The counterparty derives the same secret from their view key and the address of this view key
+    ///
+    /// @param {Address} address Address of the counterparty
+    /// @returns {Field} Shared secret
+    #[wasm_bindgen(js_name = sharedSecret)]
+    pub fn shared_secret(&self, address: &Address) -> Field {
+        Field::from((*address.to_group() * ***self).to_x_coordinate())
+    }
+
     /// Decrypt a record ciphertext with a view key
     ///
     /// @param {string} ciphertext String representation of a record ciphertext
     /// @returns {string} String representation of a record plaintext
-    pub fn decrypt(&self, ciphertext: &str) -> Result<String, String> {
-        let ciphertext = RecordCiphertext::from_str(ciphertext).map_err(|error| error.to_string())?;
-        match ciphertext.decrypt(self) {
-            Ok(plaintext) => Ok(plaintext.to_string()),
-            Err(error) => Err(error),
-        }
+    pub fn decrypt(&self, ciphertext: &str) -> Result<String, AleoError> {
+        let ciphertext = RecordCiphertext::from_str(ciphertext).map_err(AleoError::parse)?;
+        Ok(ciphertext.decrypt(self)?.to_string())
+    }
+}
+
+impl From<ViewKeyNative> for ViewKey {
+    fn from(view_key: ViewKeyNative) -> Self {
+        Self(view_key)
     }
 }
 
@@ -119,7 +132,7 @@ mod tests {
 
     #[wasm_bindgen_test]
     pub fn test_decrypt_success() {
-        let view_key = ViewKey::from_string(OWNER_VIEW_KEY);
+        let view_key = ViewKey::from_string(OWNER_VIEW_KEY).unwrap();
         let plaintext = view_key.decrypt(OWNER_CIPHERTEXT);
         plaintext.clone().unwrap();
         assert!(plaintext.is_ok());
@@ -129,8 +142,23 @@ mod tests {
     #[wasm_bindgen_test]
     pub fn test_decrypt_fails() {
         let ciphertext = RecordCiphertext::from_str(OWNER_CIPHERTEXT).map_err(|error| error.to_string()).unwrap();
-        let incorrect_view_key = ViewKey::from_string(NON_OWNER_VIEW_KEY);
+        let incorrect_view_key = ViewKey::from_string(NON_OWNER_VIEW_KEY).unwrap();
         let plaintext = ciphertext.decrypt(&incorrect_view_key);
         assert!(plaintext.is_err());
     }
+
+    #[wasm_bindgen_test]
+    pub fn test_shared_secret() {
+        let alice = ViewKey::from_private_key(&PrivateKey::new());
+        let bob = ViewKey::from_private_key(&PrivateKey::new());
+        let carol = ViewKey::from_private_key(&PrivateKey::new());
+        assert_eq!(alice.shared_secret(&bob.to_address()), bob.shared_secret(&alice.to_address()));
+        assert_ne!(alice.shared_secret(&bob.to_address()), alice.shared_secret(&carol.to_address()));
+    }
+
+    #[wasm_bindgen_test]
+    pub fn test_from_string_invalid() {
+        let error = ViewKey::from_string("AViewKey1invalid").unwrap_err();
+        assert_eq!(error.code(), crate::ErrorCode::Parse);
+    }
 }
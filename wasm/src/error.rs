@@ -0,0 +1,167 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use js_sys::Reflect;
+use std::fmt;
+use wasm_bindgen::JsValue;
+
+/// Stable codes identifying the kind of failure behind an `AleoError`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ErrorCode {
+    /// A string or byte representation could not be parsed into an Aleo type
+    Parse,
+    /// A well formed input was not valid for the requested operation
+    InvalidInput,
+    /// A record does not hold enough credits to pay an amount or fee
+    InsufficientBalance,
+    /// A proving or verifying key was missing
+    MissingKey,
+    /// A query to an Aleo network node failed
+    Query,
+    /// Authorizing, executing, proving or verifying a program execution failed
+    Proving,
+    /// A value could not be written into its javascript or byte representation
+    Serialization,
+    /// Any other failure
+    Unknown,
+}
+
+impl ErrorCode {
+    /// Returns the code exposed to javascript as the `code` property of the error
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Parse => "PARSE_ERROR",
+            Self::InvalidInput => "INVALID_INPUT",
+            Self::InsufficientBalance => "INSUFFICIENT_BALANCE",
+            Self::MissingKey => "MISSING_KEY",
+            Self::Query => "QUERY_ERROR",
+            Self::Proving => "PROVING_ERROR",
+            Self::Serialization => "SERIALIZATION_ERROR",
+            Self::Unknown => "UNKNOWN_ERROR",
+        }
+    }
+}
+
+/// Error returned by the Aleo SDK. In javascript it is thrown as an `Error` named `AleoError` with a
+/// `code` property holding one of the stable codes of `ErrorCode`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AleoError {
+    code: ErrorCode,
+    message: String,
+}
+
+impl AleoError {
+    /// Create an error with a code and a message
+    pub fn new(code: ErrorCode, message: impl fmt::Display) -> Self {
+        Self { code, message: message.to_string() }
+    }
+
+    /// Create an error for an input that could not be parsed
+    pub fn parse(message: impl fmt::Display) -> Self {
+        Self::new(ErrorCode::Parse, message)
+    }
+
+    /// Create an error for an input that is not valid for the requested operation
+    pub fn invalid_input(message: impl fmt::Display) -> Self {
+        Self::new(ErrorCode::InvalidInput, message)
+    }
+
+    /// Create an error for a record that cannot pay an amount or fee
+    pub fn insufficient_balance(message: impl fmt::Display) -> Self {
+        Self::new(ErrorCode::InsufficientBalance, message)
+    }
+
+    /// Create an error for a missing proving or verifying key
+    pub fn missing_key(message: impl fmt::Display) -> Self {
+        Self::new(ErrorCode::MissingKey, message)
+    }
+
+    /// Create an error for a failed network query
+    pub fn query(message: impl fmt::Display) -> Self {
+        Self::new(ErrorCode::Query, message)
+    }
+
+    /// Create an error for a failed authorization, execution, proof or verification
+    pub fn proving(message: impl fmt::Display) -> Self {
+        Self::new(ErrorCode::Proving, message)
+    }
+
+    /// Create an error for a value that could not be written into javascript or bytes
+    pub fn serialization(message: impl fmt::Display) -> Self {
+        Self::new(ErrorCode::Serialization, message)
+    }
+
+    /// Returns the code of the error
+    pub fn code(&self) -> ErrorCode {
+        self.code
+    }
+
+    /// Returns the message of the error
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for AleoError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for AleoError {}
+
+impl From<String> for AleoError {
+    fn from(message: String) -> Self {
+        Self::new(ErrorCode::Unknown, message)
+    }
+}
+
+impl From<&str> for AleoError {
+    fn from(message: &str) -> Self {
+        Self::new(ErrorCode::Unknown, message)
+    }
+}
+
+impl From<AleoError> for JsValue {
+    fn from(error: AleoError) -> Self {
+        let js_error = js_sys::Error::new(&error.message);
+        js_error.set_name("AleoError");
+        let _ = Reflect::set(&js_error, &JsValue::from_str("code"), &JsValue::from_str(error.code.as_str()));
+        js_error.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn test_error_codes_and_conversions() {
+        let error = AleoError::insufficient_balance("Fee record does not have enough credits");
+        assert_eq!(error.code(), ErrorCode::InsufficientBalance);
+        assert_eq!(error.code().as_str(), "INSUFFICIENT_BALANCE");
+        assert_eq!(error.to_string(), "Fee record does not have enough credits");
+        assert_eq!(error.message(), "Fee record does not have enough credits");
+        assert_eq!(AleoError::serialization("Failed to set property").code().as_str(), "SERIALIZATION_ERROR");
+
+        // Assert untyped errors are converted to the unknown code
+        let error = AleoError::from("Something went wrong".to_string());
+        assert_eq!(error.code(), ErrorCode::Unknown);
+        assert_eq!(error.message(), "Something went wrong");
+    }
+}
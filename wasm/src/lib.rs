@@ -155,6 +155,9 @@
 pub mod account;
 pub use account::*;
 
+pub mod error;
+pub use error::*;
+
 pub mod programs;
 pub use programs::*;
 
@@ -162,7 +165,7 @@ pub mod record;
 pub use record::*;
 
 pub mod types;
-pub use types::Field;
+pub use types::{Field, Group, Scalar};
 
 #[cfg(not(test))]
 mod thread_pool;
@@ -184,9 +187,7 @@ mod thread_pool {
 
 use wasm_bindgen::prelude::*;
 
-use std::str::FromStr;
-
-use types::native::RecordPlaintextNative;
+use types::native::{self, RecordPlaintextNative};
 
 // Facilities for cross-platform logging in both web browsers and nodeJS
 #[wasm_bindgen]
@@ -199,24 +200,55 @@ extern "C" {
 /// A trait providing convenient methods for accessing the amount of Aleo present in a record
 pub trait Credits {
     /// Get the amount of credits in the record if the record possesses Aleo credits
-    fn credits(&self) -> Result<f64, String> {
+    fn credits(&self) -> Result<f64, AleoError> {
         Ok(self.microcredits()? as f64 / 1_000_000.0)
     }
 
     /// Get the amount of microcredits in the record if the record possesses Aleo credits
-    fn microcredits(&self) -> Result<u64, String>;
+    fn microcredits(&self) -> Result<u64, AleoError>;
 }
 
 impl Credits for RecordPlaintextNative {
-    fn microcredits(&self) -> Result<u64, String> {
-        match self
-            .find(&[native::IdentifierNative::from_str("microcredits").map_err(|e| e.to_string())?])
-            .map_err(|e| e.to_string())?
-        {
-            native::Entry::Private(native::PlaintextNative::Literal(native::LiteralNative::U64(amount), _)) => {
-                Ok(*amount)
+    fn microcredits(&self) -> Result<u64, AleoError> {
+        // The credits balance spec guarantees a u64 balance
+        Ok(self.balance(&BalanceSpec::credits())? as u64)
+    }
+}
+
+/// A trait providing access to the balance of records of any program, such as token records
+pub trait Balance {
+    /// Get the balance held by the record at the location described by a balance spec
+    fn balance(&self, spec: &BalanceSpec) -> Result<u128, AleoError>;
+}
+
+impl Balance for RecordPlaintextNative {
+    fn balance(&self, spec: &BalanceSpec) -> Result<u128, AleoError> {
+        let missing_balance = || {
+            AleoError::invalid_input(format!(
+                "The record provided does not contain a {} field of type {}",
+                spec.path(),
+                spec.integer_type()
+            ))
+        };
+        let literal = match record::record_plaintext::find_member(self, spec.member_path()) {
+            Ok((native::PlaintextNative::Literal(literal, _), _)) if literal.to_type() == spec.literal_type() => {
+                literal
             }
-            _ => Err("The record provided does not contain a microcredits field".to_string()),
+            _ => return Err(missing_balance()),
+        };
+        let negative_balance = |_| AleoError::invalid_input("The record provided has a negative balance");
+        match literal {
+            native::LiteralNative::I8(amount) => u128::try_from(**amount).map_err(negative_balance),
+            native::LiteralNative::I16(amount) => u128::try_from(**amount).map_err(negative_balance),
+            native::LiteralNative::I32(amount) => u128::try_from(**amount).map_err(negative_balance),
+            native::LiteralNative::I64(amount) => u128::try_from(**amount).map_err(negative_balance),
+            native::LiteralNative::I128(amount) => u128::try_from(**amount).map_err(negative_balance),
+            native::LiteralNative::U8(amount) => Ok(**amount as u128),
+            native::LiteralNative::U16(amount) => Ok(**amount as u128),
+            native::LiteralNative::U32(amount) => Ok(**amount as u128),
+            native::LiteralNative::U64(amount) => Ok(**amount as u128),
+            native::LiteralNative::U128(amount) => Ok(**amount),
+            _ => Err(missing_balance()),
         }
     }
 }
@@ -224,7 +256,6 @@ impl Credits for RecordPlaintextNative {
 #[cfg(not(test))]
 #[doc(hidden)]
 pub use thread_pool::run_rayon_thread;
-use types::native;
 
 #[cfg(not(test))]
 #[wasm_bindgen(js_name = "initThreadPool")]
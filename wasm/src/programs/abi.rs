@@ -0,0 +1,525 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::value_type::{DeclaredType, SplitVisibility};
+use crate::AleoError;
+
+use crate::types::native::{
+    CurrentNetwork,
+    Entry,
+    PlaintextNative,
+    PlaintextType,
+    ProgramNative,
+    RecordPlaintextNative,
+};
+use js_sys::Array;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use wasm_bindgen::prelude::*;
+
+/// Version of the program ABI format
+const ABI_VERSION: u8 = 1;
+
+/// Type of a value in the ABI
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+enum TypeAbi {
+    Literal {
+        #[serde(rename = "type")]
+        literal_type: String,
+    },
+    Struct {
+        name: String,
+    },
+    Array {
+        element: Box<TypeAbi>,
+        length: u32,
+    },
+    Record {
+        name: String,
+    },
+    ExternalRecord {
+        locator: String,
+    },
+    Future {
+        locator: String,
+    },
+}
+
+/// Named member of a struct
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+struct MemberAbi {
+    name: String,
+    #[serde(rename = "type")]
+    value_type: TypeAbi,
+}
+
+/// Struct declared by the program
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+struct StructAbi {
+    name: String,
+    members: Vec<MemberAbi>,
+}
+
+/// Entry of a record along with its visibility
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+struct EntryAbi {
+    name: String,
+    visibility: String,
+    #[serde(rename = "type")]
+    value_type: TypeAbi,
+}
+
+/// Record declared by the program
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+struct RecordAbi {
+    name: String,
+    owner: String,
+    entries: Vec<EntryAbi>,
+}
+
+/// Mapping declared by the program
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+struct MappingAbi {
+    name: String,
+    key: TypeAbi,
+    value: TypeAbi,
+}
+
+/// Input or output of a function or finalize block. Records and futures have no visibility
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+struct ValueAbi {
+    register: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    visibility: Option<String>,
+    #[serde(rename = "type")]
+    value_type: TypeAbi,
+}
+
+/// Function declared by the program, with the inputs of its finalize block if it is asynchronous
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+struct FunctionAbi {
+    name: String,
+    inputs: Vec<ValueAbi>,
+    outputs: Vec<ValueAbi>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    finalize: Option<Vec<ValueAbi>>,
+}
+
+/// Machine readable description of the interface of a program: its functions with the types and
+/// visibilities of their inputs and outputs, the inputs of their finalize blocks, and the records,
+/// structs and mappings of the program. It can be used to validate the inputs of an execution
+/// without the source code of the program
+///
+/// ```json
+/// {
+///   "version": 1,
+///   "program": "token.aleo",
+///   "imports": [],
+///   "structs": [{ "name": "metadata", "members": [{ "name": "id", "type": { "kind": "literal", "type": "field" } }] }],
+///   "records": [{ "name": "token", "owner": "private", "entries": [...] }],
+///   "mappings": [{ "name": "balances", "key": { "kind": "literal", "type": "address" }, "value": {...} }],
+///   "functions": [{ "name": "mint", "inputs": [{ "register": "r0", "visibility": "private", "type": {...} }], "outputs": [...] }]
+/// }
+/// ```
+#[wasm_bindgen]
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct ProgramAbi {
+    version: u8,
+    program: String,
+    imports: Vec<String>,
+    structs: Vec<StructAbi>,
+    records: Vec<RecordAbi>,
+    mappings: Vec<MappingAbi>,
+    functions: Vec<FunctionAbi>,
+}
+
+#[wasm_bindgen]
+impl ProgramAbi {
+    /// Returns the ID of the program described by the ABI
+    ///
+    /// @returns {string} Program ID
+    pub fn program(&self) -> String {
+        self.program.clone()
+    }
+
+    /// Returns the JSON string representation of the ABI
+    ///
+    /// @returns {string} ABI JSON
+    #[allow(clippy::inherent_to_string)]
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_string(&self) -> String {
+        serde_json::to_string(&self).unwrap()
+    }
+
+    /// Read an ABI from its JSON string representation
+    ///
+    /// @param {string} abi ABI JSON
+    /// @returns {ProgramAbi} Program ABI
+    #[wasm_bindgen(js_name = fromString)]
+    pub fn from_string(abi: &str) -> Result<ProgramAbi, AleoError> {
+        let abi: ProgramAbi = serde_json::from_str(abi).map_err(AleoError::parse)?;
+        if abi.version != ABI_VERSION {
+            return Err(AleoError::parse(format!("Unsupported ABI version {}", abi.version)));
+        }
+        Ok(abi)
+    }
+
+    /// Check that a set of encoded inputs matches the inputs of a function, as they would be passed
+    /// to `ProgramManager`. Plaintext inputs must be of the declared types, and record inputs must
+    /// be record plaintexts holding the declared entries
+    ///
+    /// @param {string} function_name Name of the function
+    /// @param {Array} inputs Array of encoded inputs
+    ///
+    /// @example
+    ///
+    /// const abi = ProgramAbi.fromString(program.toAbi());
+    /// abi.checkInputs("transfer_public", ["aleo1...", "5u64"]); // throws an AleoError if an input is invalid
+    #[wasm_bindgen(js_name = checkInputs)]
+    pub fn check_inputs(&self, function_name: &str, inputs: Array) -> Result<(), AleoError> {
+        let function = self.functions.iter().find(|function| function.name == function_name).ok_or_else(|| {
+            AleoError::invalid_input(format!("function {function_name} not found in {}", self.program))
+        })?;
+        if inputs.length() as usize != function.inputs.len() {
+            return Err(AleoError::invalid_input(format!(
+                "Function {function_name} expects {} inputs, found {}",
+                function.inputs.len(),
+                inputs.length()
+            )));
+        }
+        for (input, input_abi) in inputs.iter().zip(function.inputs.iter()) {
+            let register = &input_abi.register;
+            let input = input
+                .as_string()
+                .ok_or_else(|| AleoError::invalid_input(format!("Input {register} must be a string")))?;
+            self.check_input(&input, input_abi)
+                .map_err(|error| AleoError::invalid_input(format!("Input {register}: {error}")))?;
+        }
+        Ok(())
+    }
+}
+
+impl ProgramAbi {
+    /// Describe the interface of a program
+    pub(crate) fn from_program(program: &ProgramNative) -> Self {
+        let structs = program
+            .structs()
+            .iter()
+            .map(|(name, program_struct)| StructAbi {
+                name: name.to_string(),
+                members: program_struct
+                    .members()
+                    .iter()
+                    .map(|(member, member_type)| MemberAbi {
+                        name: member.to_string(),
+                        value_type: plaintext_abi(member_type),
+                    })
+                    .collect(),
+            })
+            .collect();
+
+        let records = program
+            .records()
+            .iter()
+            .map(|(name, record)| RecordAbi {
+                name: name.to_string(),
+                owner: if record.owner().is_public() { "public" } else { "private" }.to_string(),
+                entries: record
+                    .entries()
+                    .iter()
+                    .map(|(entry, entry_type)| {
+                        let (visibility, declared_type) = entry_type.split_visibility();
+                        EntryAbi {
+                            name: entry.to_string(),
+                            visibility: visibility.unwrap_or_default().to_string(),
+                            value_type: type_abi(declared_type),
+                        }
+                    })
+                    .collect(),
+            })
+            .collect();
+
+        let mappings = program
+            .mappings()
+            .iter()
+            .map(|(name, mapping)| MappingAbi {
+                name: name.to_string(),
+                key: plaintext_abi(mapping.key().plaintext_type()),
+                value: plaintext_abi(mapping.value().plaintext_type()),
+            })
+            .collect();
+
+        let functions = program
+            .functions()
+            .values()
+            .map(|function| FunctionAbi {
+                name: function.name().to_string(),
+                inputs: function
+                    .inputs()
+                    .iter()
+                    .map(|input| value_abi(input.register().to_string(), input.value_type()))
+                    .collect(),
+                outputs: function
+                    .outputs()
+                    .iter()
+                    .map(|output| value_abi(output.operand().to_string(), output.value_type()))
+                    .collect(),
+                finalize: function.finalize_logic().map(|finalize| {
+                    finalize
+                        .inputs()
+                        .iter()
+                        .map(|input| value_abi(input.register().to_string(), input.finalize_type()))
+                        .collect()
+                }),
+            })
+            .collect();
+
+        Self {
+            version: ABI_VERSION,
+            program: program.id().to_string(),
+            imports: program.imports().keys().map(|import| import.to_string()).collect(),
+            structs,
+            records,
+            mappings,
+            functions,
+        }
+    }
+
+    // Check an encoded input against its declaration
+    fn check_input(&self, input: &str, input_abi: &ValueAbi) -> Result<(), String> {
+        match &input_abi.value_type {
+            TypeAbi::Record { name } => {
+                let record =
+                    RecordPlaintextNative::from_str(input).map_err(|_| "expected a record plaintext".to_string())?;
+                self.check_record(&record, name)
+            }
+            TypeAbi::ExternalRecord { locator } => RecordPlaintextNative::from_str(input)
+                .map(|_| ())
+                .map_err(|_| format!("expected a {locator} record plaintext")),
+            TypeAbi::Future { .. } => Err("futures cannot be passed as inputs".to_string()),
+            value_type => {
+                let plaintext =
+                    PlaintextNative::from_str(input).map_err(|_| "expected a plaintext value".to_string())?;
+                self.check_plaintext(&plaintext, value_type)
+            }
+        }
+    }
+
+    // Check that a record plaintext holds the entries of a record of the program
+    fn check_record(&self, record: &RecordPlaintextNative, name: &str) -> Result<(), String> {
+        let record_abi = self
+            .records
+            .iter()
+            .find(|record| record.name == name)
+            .ok_or_else(|| format!("record {name} not found in {}", self.program))?;
+        let owner = if record.owner().is_public() { "public" } else { "private" };
+        if owner != record_abi.owner {
+            return Err(format!("expected a {} owner, found a {owner} owner", record_abi.owner));
+        }
+        if record.data().len() != record_abi.entries.len() {
+            return Err(format!("expected {} entries in record {name}", record_abi.entries.len()));
+        }
+        for ((entry, value), entry_abi) in record.data().iter().zip(record_abi.entries.iter()) {
+            let (visibility, plaintext) = match value {
+                Entry::Constant(plaintext) => ("constant", plaintext),
+                Entry::Public(plaintext) => ("public", plaintext),
+                Entry::Private(plaintext) => ("private", plaintext),
+            };
+            if entry.to_string() != entry_abi.name || visibility != entry_abi.visibility {
+                return Err(format!("expected the {} entry {} in record {name}", entry_abi.visibility, entry_abi.name));
+            }
+            self.check_plaintext(plaintext, &entry_abi.value_type).map_err(|error| format!("{entry}: {error}"))?;
+        }
+        Ok(())
+    }
+
+    // Check that a plaintext is of a type of the ABI
+    fn check_plaintext(&self, plaintext: &PlaintextNative, value_type: &TypeAbi) -> Result<(), String> {
+        match (value_type, plaintext) {
+            (TypeAbi::Literal { literal_type }, PlaintextNative::Literal(literal, ..)) => {
+                if literal.to_type().to_string() == *literal_type {
+                    Ok(())
+                } else {
+                    Err(format!("expected a {literal_type}, found {literal}"))
+                }
+            }
+            (TypeAbi::Struct { name }, PlaintextNative::Struct(members, ..)) => {
+                let struct_abi = self
+                    .structs
+                    .iter()
+                    .find(|program_struct| program_struct.name == *name)
+                    .ok_or_else(|| format!("struct {name} not found in {}", self.program))?;
+                let names_match = members.len() == struct_abi.members.len()
+                    && members
+                        .keys()
+                        .zip(struct_abi.members.iter())
+                        .all(|(member, abi)| member.to_string() == abi.name);
+                if !names_match {
+                    return Err(format!("expected the members of struct {name}"));
+                }
+                for ((member, value), member_abi) in members.iter().zip(struct_abi.members.iter()) {
+                    self.check_plaintext(value, &member_abi.value_type)
+                        .map_err(|error| format!("{member}: {error}"))?;
+                }
+                Ok(())
+            }
+            (TypeAbi::Array { element, length }, PlaintextNative::Array(elements, ..)) => {
+                if elements.len() != *length as usize {
+                    return Err(format!("expected an array of {length} elements, found {}", elements.len()));
+                }
+                elements.iter().try_for_each(|value| self.check_plaintext(value, element))
+            }
+            (TypeAbi::Literal { literal_type }, _) => Err(format!("expected a {literal_type}")),
+            (TypeAbi::Struct { name }, _) => Err(format!("expected a {name} struct")),
+            (TypeAbi::Array { .. }, _) => Err("expected an array".to_string()),
+            _ => Err("expected a record or future".to_string()),
+        }
+    }
+}
+
+// Describe a plaintext type in the ABI
+fn plaintext_abi(plaintext_type: &PlaintextType<CurrentNetwork>) -> TypeAbi {
+    match plaintext_type {
+        PlaintextType::Literal(literal_type) => TypeAbi::Literal { literal_type: literal_type.to_string() },
+        PlaintextType::Struct(struct_id) => TypeAbi::Struct { name: struct_id.to_string() },
+        PlaintextType::Array(array_type) => TypeAbi::Array {
+            element: Box::new(plaintext_abi(array_type.next_element_type())),
+            length: **array_type.length(),
+        },
+    }
+}
+
+// Describe the type of a value declared by the program in the ABI
+fn type_abi(declared_type: DeclaredType) -> TypeAbi {
+    match declared_type {
+        DeclaredType::Plaintext(plaintext_type) => plaintext_abi(plaintext_type),
+        DeclaredType::Record(record_id) => TypeAbi::Record { name: record_id.to_string() },
+        DeclaredType::ExternalRecord(locator) => TypeAbi::ExternalRecord { locator: locator.to_string() },
+        DeclaredType::Future(locator) => TypeAbi::Future { locator: locator.to_string() },
+    }
+}
+
+// Describe a function input or output or a finalize input in the ABI
+fn value_abi(register: String, value_type: &impl SplitVisibility) -> ValueAbi {
+    let (visibility, declared_type) = value_type.split_visibility();
+    ValueAbi { register, visibility: visibility.map(String::from), value_type: type_abi(declared_type) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use wasm_bindgen_test::*;
+
+    const OWNER: &str = "aleo1j7qxyunfldj2lp8hsvy7mw5k8zaqgjfyr72x2gh3x4ewgae8v5gscf5jh3";
+    const NONCE: &str = "3077450429259593211617823051143573281856129402760267155982965992208217472983group";
+
+    const TOKEN_PROGRAM: &str = r#"program token_abi.aleo;
+
+struct metadata:
+    id as field;
+    flags as [boolean; 2u32];
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+    data as metadata.public;
+
+mapping supply:
+    key as field.public;
+    value as u64.public;
+
+function mint:
+    input r0 as address.private;
+    input r1 as u64.private;
+    input r2 as metadata.public;
+    cast r0 r1 r2 into r3 as token.record;
+    output r3 as token.record;
+
+function burn:
+    input r0 as token.record;
+    async burn r0.amount into r1;
+    output r1 as token_abi.aleo/burn.future;
+
+finalize burn:
+    input r0 as u64.public;
+    get.or_use supply[0field] 0u64 into r1;
+    sub r1 r0 into r2;
+    set r2 into supply[0field];"#;
+
+    fn strings(values: &[&str]) -> Array {
+        values.iter().map(|value| JsValue::from_str(value)).collect()
+    }
+
+    #[wasm_bindgen_test]
+    fn test_program_abi() {
+        let abi = ProgramAbi::from_program(&ProgramNative::from_str(TOKEN_PROGRAM).unwrap());
+        let json: serde_json::Value = serde_json::from_str(&abi.to_string()).unwrap();
+        assert_eq!(json["version"], 1);
+        assert_eq!(json["program"], "token_abi.aleo");
+        assert_eq!(
+            json["structs"][0]["members"][1]["type"],
+            serde_json::json!({
+                "kind": "array",
+                "element": { "kind": "literal", "type": "boolean" },
+                "length": 2
+            })
+        );
+        assert_eq!(json["records"][0]["entries"][1]["visibility"], "public");
+        assert_eq!(json["mappings"][0]["value"]["type"], "u64");
+        assert_eq!(
+            json["functions"][0]["inputs"][2],
+            serde_json::json!({
+                "register": "r2",
+                "visibility": "public",
+                "type": { "kind": "struct", "name": "metadata" }
+            })
+        );
+        assert_eq!(
+            json["functions"][0]["outputs"][0]["type"],
+            serde_json::json!({ "kind": "record", "name": "token" })
+        );
+        assert!(json["functions"][0].get("finalize").is_none());
+        assert_eq!(json["functions"][1]["finalize"][0]["type"]["type"], "u64");
+
+        assert_eq!(ProgramAbi::from_string(&abi.to_string()).unwrap(), abi);
+        assert!(ProgramAbi::from_string(&abi.to_string().replace("\"version\":1", "\"version\":2")).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_program_abi_checks_inputs() {
+        let abi = ProgramAbi::from_program(&ProgramNative::from_str(TOKEN_PROGRAM).unwrap());
+        let metadata = "{ id: 1field, flags: [true, false] }";
+        assert!(abi.check_inputs("mint", strings(&[OWNER, "5u64", metadata])).is_ok());
+
+        // Assert inputs of the wrong types, count or shape are rejected
+        let error = abi.check_inputs("mint", strings(&[OWNER, "5u32", metadata])).unwrap_err();
+        assert_eq!(error.code(), crate::ErrorCode::InvalidInput);
+        assert!(abi.check_inputs("mint", strings(&[OWNER, "5u64"])).is_err());
+        assert!(abi.check_inputs("mint", strings(&[OWNER, "5u64", "{ id: 1field, flags: [true] }"])).is_err());
+        assert!(abi.check_inputs("mint", strings(&[OWNER, "5u64", "{ flags: [true, false], id: 1field }"])).is_err());
+        assert!(abi.check_inputs("missing", strings(&[])).is_err());
+
+        let record = format!(
+            "{{ owner: {OWNER}.private, amount: 5u64.private, data: {{ id: 1field.public, flags: [true.public, false.public] }}, _nonce: {NONCE}.public }}"
+        );
+        assert!(abi.check_inputs("burn", strings(&[&record])).is_ok());
+        assert!(abi.check_inputs("burn", strings(&[&record.replace("5u64.private", "5u64.public")])).is_err());
+        assert!(abi.check_inputs("burn", strings(&[&record.replace("5u64.private", "5u128.private")])).is_err());
+        assert!(abi.check_inputs("burn", strings(&["5u64"])).is_err());
+    }
+}
@@ -0,0 +1,382 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::AleoError;
+
+use crate::types::native::{CallOperator, Instruction, ProgramIDNative, ProgramNative};
+use indexmap::{IndexMap, IndexSet};
+use js_sys::{Array, Object, Reflect};
+use std::str::FromStr;
+use wasm_bindgen::prelude::*;
+
+/// Call of a function of an imported program by a function of a program of the suite
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct ExternalCall {
+    caller: String,
+    callee: String,
+}
+
+/// Analysis of a suite of interdependent programs. It builds the import graph and the cross-program
+/// call graph of the suite, detects import cycles and imports which are neither part of the suite
+/// nor already deployed, and computes the order in which the suite must be deployed
+#[wasm_bindgen]
+pub struct ProgramAnalysis {
+    imports: IndexMap<ProgramIDNative, Vec<ProgramIDNative>>,
+    calls: Vec<ExternalCall>,
+    missing_imports: Vec<(ProgramIDNative, ProgramIDNative)>,
+    cycles: Vec<Vec<ProgramIDNative>>,
+}
+
+#[wasm_bindgen]
+impl ProgramAnalysis {
+    /// Analyze a suite of programs. Imports of programs which are already deployed, such as
+    /// credits.aleo, are not required to be part of the suite
+    ///
+    /// @param {Array} programs Array of the program source codes of the suite
+    /// @param {Array | undefined} deployed Program IDs of programs which are already deployed
+    /// @returns {ProgramAnalysis} Analysis of the suite
+    ///
+    /// @example
+    ///
+    /// const analysis = ProgramAnalysis.analyze([token_program, exchange_program]);
+    /// const order = analysis.deploymentOrder(); // ["token.aleo", "exchange.aleo"]
+    pub fn analyze(programs: Array, deployed: Option<Array>) -> Result<ProgramAnalysis, AleoError> {
+        let mut suite = IndexMap::new();
+        for program in programs.iter() {
+            let program =
+                program.as_string().ok_or_else(|| AleoError::invalid_input("Every program must be a string"))?;
+            let program = ProgramNative::from_str(&program).map_err(AleoError::parse)?;
+            if suite.insert(*program.id(), program.clone()).is_some() {
+                return Err(AleoError::invalid_input(format!("{} is part of the suite more than once", program.id())));
+            }
+        }
+
+        let mut deployed_ids = IndexSet::new();
+        deployed_ids.insert(ProgramIDNative::from_str("credits.aleo").unwrap());
+        for program_id in deployed.iter().flat_map(|deployed| deployed.iter()) {
+            let program_id =
+                program_id.as_string().ok_or_else(|| AleoError::invalid_input("Every program ID must be a string"))?;
+            let program_id = ProgramIDNative::from_str(&program_id)
+                .map_err(|_| AleoError::parse(format!("{program_id} is an invalid program name")))?;
+            deployed_ids.insert(program_id);
+        }
+
+        let imports = suite
+            .iter()
+            .map(|(program_id, program)| (*program_id, program.imports().keys().copied().collect::<Vec<_>>()))
+            .collect::<IndexMap<_, _>>();
+        let missing_imports = imports
+            .iter()
+            .flat_map(|(program_id, imports)| imports.iter().map(move |import| (*program_id, *import)))
+            .filter(|(_, import)| !suite.contains_key(import) && !deployed_ids.contains(import))
+            .collect();
+        let calls = suite.values().flat_map(external_calls).collect();
+        let cycles = find_cycles(&imports);
+
+        Ok(Self { imports, calls, missing_imports, cycles })
+    }
+
+    /// Get the import graph of the suite as an object mapping every program ID to the IDs of the
+    /// programs it imports directly
+    ///
+    /// @returns {Object} Import graph
+    #[wasm_bindgen(js_name = "getImportGraph")]
+    pub fn get_import_graph(&self) -> Result<Object, AleoError> {
+        let graph = Object::new();
+        for (program_id, imports) in &self.imports {
+            let imports = imports.iter().map(|import| JsValue::from_str(&import.to_string())).collect::<Array>();
+            Reflect::set(&graph, &program_id.to_string().into(), &imports)
+                .map_err(|_| AleoError::serialization("Failed to set property"))?;
+        }
+        Ok(graph)
+    }
+
+    /// Get the cross-program call graph of the suite, made of every `call` instruction of a function
+    /// to a function of another program
+    ///
+    /// @returns {Array} Array of calls of the form `{ caller: "exchange.aleo/swap", callee: "token.aleo/transfer" }`
+    #[wasm_bindgen(js_name = "getCallGraph")]
+    pub fn get_call_graph(&self) -> Result<Array, AleoError> {
+        let calls = Array::new();
+        for call in &self.calls {
+            let object = Object::new();
+            Reflect::set(&object, &"caller".into(), &call.caller.as_str().into())
+                .map_err(|_| AleoError::serialization("Failed to set property"))?;
+            Reflect::set(&object, &"callee".into(), &call.callee.as_str().into())
+                .map_err(|_| AleoError::serialization("Failed to set property"))?;
+            calls.push(&object);
+        }
+        Ok(calls)
+    }
+
+    /// Get the imports which are neither part of the suite nor already deployed
+    ///
+    /// @returns {Array} Array of missing imports of the form `{ program: "exchange.aleo", import: "token.aleo" }`
+    #[wasm_bindgen(js_name = "getMissingImports")]
+    pub fn get_missing_imports(&self) -> Result<Array, AleoError> {
+        let missing_imports = Array::new();
+        for (program_id, import) in &self.missing_imports {
+            let object = Object::new();
+            Reflect::set(&object, &"program".into(), &program_id.to_string().into())
+                .map_err(|_| AleoError::serialization("Failed to set property"))?;
+            Reflect::set(&object, &"import".into(), &import.to_string().into())
+                .map_err(|_| AleoError::serialization("Failed to set property"))?;
+            missing_imports.push(&object);
+        }
+        Ok(missing_imports)
+    }
+
+    /// Get the import cycles of the suite. Every cycle is an array of the IDs of the programs which
+    /// import each other, directly or indirectly
+    ///
+    /// @returns {Array} Array of import cycles
+    #[wasm_bindgen(js_name = "getCycles")]
+    pub fn get_cycles(&self) -> Array {
+        self.cycles
+            .iter()
+            .map(|cycle| cycle.iter().map(|program_id| JsValue::from_str(&program_id.to_string())).collect::<Array>())
+            .collect()
+    }
+
+    /// Get the order in which the programs of the suite must be deployed, every program being
+    /// deployed after the programs it imports. Programs which do not depend on each other keep the
+    /// order they were given in
+    ///
+    /// @returns {Array} Array of program IDs in deployment order
+    #[wasm_bindgen(js_name = "deploymentOrder")]
+    pub fn deployment_order(&self) -> Result<Array, AleoError> {
+        if let Some((program_id, import)) = self.missing_imports.first() {
+            return Err(AleoError::invalid_input(format!(
+                "{program_id} imports {import}, which is neither part of the suite nor deployed"
+            )));
+        }
+        if let Some(cycle) = self.cycles.first() {
+            let cycle = cycle.iter().map(|program_id| program_id.to_string()).collect::<Vec<_>>();
+            return Err(AleoError::invalid_input(format!("The programs {} import each other", cycle.join(", "))));
+        }
+        Ok(self.topological_order().iter().map(|program_id| JsValue::from_str(&program_id.to_string())).collect())
+    }
+}
+
+impl ProgramAnalysis {
+    // Order the programs of an acyclic suite so that every program follows the programs it imports
+    fn topological_order(&self) -> Vec<ProgramIDNative> {
+        let mut order = IndexSet::with_capacity(self.imports.len());
+        while order.len() < self.imports.len() {
+            // Deploy the first program whose imports from the suite are all deployed
+            let next = self.imports.iter().find(|(program_id, imports)| {
+                !order.contains(*program_id)
+                    && imports.iter().all(|import| order.contains(import) || !self.imports.contains_key(import))
+            });
+            match next {
+                Some((program_id, _)) => order.insert(*program_id),
+                None => break,
+            };
+        }
+        order.into_iter().collect()
+    }
+}
+
+// Collect the calls of the functions of a program to functions of other programs
+fn external_calls(program: &ProgramNative) -> Vec<ExternalCall> {
+    let mut calls = Vec::new();
+    for function in program.functions().values() {
+        for instruction in function.instructions() {
+            if let Instruction::Call(call) = instruction {
+                if let CallOperator::Locator(locator) = call.operator() {
+                    calls.push(ExternalCall {
+                        caller: format!("{}/{}", program.id(), function.name()),
+                        callee: locator.to_string(),
+                    });
+                }
+            }
+        }
+    }
+    calls
+}
+
+// Find the strongly connected components of the import graph which form cycles, using Tarjan's
+// algorithm. A program importing itself is a cycle of one program
+fn find_cycles(imports: &IndexMap<ProgramIDNative, Vec<ProgramIDNative>>) -> Vec<Vec<ProgramIDNative>> {
+    struct Tarjan<'a> {
+        imports: &'a IndexMap<ProgramIDNative, Vec<ProgramIDNative>>,
+        index: usize,
+        indices: Vec<Option<usize>>,
+        low_links: Vec<usize>,
+        stack: Vec<usize>,
+        on_stack: Vec<bool>,
+        cycles: Vec<Vec<ProgramIDNative>>,
+    }
+
+    impl Tarjan<'_> {
+        fn visit(&mut self, node: usize) {
+            self.indices[node] = Some(self.index);
+            self.low_links[node] = self.index;
+            self.index += 1;
+            self.stack.push(node);
+            self.on_stack[node] = true;
+
+            let graph = self.imports;
+            let (program_id, imports) = graph.get_index(node).unwrap();
+            for successor in imports.iter().filter_map(|import| graph.get_index_of(import)) {
+                match self.indices[successor] {
+                    None => {
+                        self.visit(successor);
+                        self.low_links[node] = self.low_links[node].min(self.low_links[successor]);
+                    }
+                    Some(index) if self.on_stack[successor] => {
+                        self.low_links[node] = self.low_links[node].min(index);
+                    }
+                    Some(_) => {}
+                }
+            }
+
+            if Some(self.low_links[node]) == self.indices[node] {
+                let mut component = Vec::new();
+                while let Some(member) = self.stack.pop() {
+                    self.on_stack[member] = false;
+                    component.push(member);
+                    if member == node {
+                        break;
+                    }
+                }
+                if component.len() > 1 || imports.contains(program_id) {
+                    component.sort_unstable();
+                    self.cycles.push(component.into_iter().map(|member| *graph.get_index(member).unwrap().0).collect());
+                }
+            }
+        }
+    }
+
+    let mut tarjan = Tarjan {
+        imports,
+        index: 0,
+        indices: vec![None; imports.len()],
+        low_links: vec![0; imports.len()],
+        stack: Vec::new(),
+        on_stack: vec![false; imports.len()],
+        cycles: Vec::new(),
+    };
+    for node in 0..imports.len() {
+        if tarjan.indices[node].is_none() {
+            tarjan.visit(node);
+        }
+    }
+    tarjan.cycles
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use wasm_bindgen_test::*;
+
+    const TOKEN_PROGRAM: &str = r#"program token_suite.aleo;
+
+function mint:
+    input r0 as u64.public;
+    output r0 as u64.public;"#;
+
+    const VAULT_PROGRAM: &str = r#"import token_suite.aleo;
+
+program vault_suite.aleo;
+
+function deposit:
+    input r0 as u64.public;
+    call token_suite.aleo/mint r0 into r1;
+    output r1 as u64.public;"#;
+
+    const EXCHANGE_PROGRAM: &str = r#"import credits.aleo;
+import token_suite.aleo;
+import vault_suite.aleo;
+
+program exchange_suite.aleo;
+
+function swap:
+    input r0 as u64.public;
+    call vault_suite.aleo/deposit r0 into r1;
+    call token_suite.aleo/mint r1 into r2;
+    output r2 as u64.public;"#;
+
+    fn strings(array: &Array) -> Vec<String> {
+        array.iter().map(|value| value.as_string().unwrap()).collect()
+    }
+
+    #[wasm_bindgen_test]
+    fn test_analysis_of_suite() {
+        let programs = Array::of3(&EXCHANGE_PROGRAM.into(), &VAULT_PROGRAM.into(), &TOKEN_PROGRAM.into());
+        let analysis = ProgramAnalysis::analyze(programs, None).unwrap();
+
+        assert_eq!(strings(&analysis.deployment_order().unwrap()), vec![
+            "token_suite.aleo",
+            "vault_suite.aleo",
+            "exchange_suite.aleo"
+        ]);
+        assert_eq!(analysis.get_missing_imports().unwrap().length(), 0);
+        assert_eq!(analysis.get_cycles().length(), 0);
+
+        let graph = analysis.get_import_graph().unwrap();
+        let exchange_imports: Array = Reflect::get(&graph, &"exchange_suite.aleo".into()).unwrap().into();
+        assert_eq!(strings(&exchange_imports), vec!["credits.aleo", "token_suite.aleo", "vault_suite.aleo"]);
+
+        let calls = analysis
+            .get_call_graph()
+            .unwrap()
+            .iter()
+            .map(|call| {
+                let caller = Reflect::get(&call, &"caller".into()).unwrap().as_string().unwrap();
+                let callee = Reflect::get(&call, &"callee".into()).unwrap().as_string().unwrap();
+                (caller, callee)
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(calls, vec![
+            ("exchange_suite.aleo/swap".to_string(), "vault_suite.aleo/deposit".to_string()),
+            ("exchange_suite.aleo/swap".to_string(), "token_suite.aleo/mint".to_string()),
+            ("vault_suite.aleo/deposit".to_string(), "token_suite.aleo/mint".to_string()),
+        ]);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_analysis_detects_missing_imports() {
+        let programs = Array::of2(&EXCHANGE_PROGRAM.into(), &VAULT_PROGRAM.into());
+        let analysis = ProgramAnalysis::analyze(programs.clone(), None).unwrap();
+        let missing = analysis.get_missing_imports().unwrap();
+        assert_eq!(missing.length(), 2);
+        assert_eq!(Reflect::get(&missing.get(0), &"import".into()).unwrap().as_string().unwrap(), "token_suite.aleo");
+        assert_eq!(analysis.deployment_order().unwrap_err().code(), crate::ErrorCode::InvalidInput);
+
+        // Assert imports of deployed programs are not missing
+        let deployed = Array::of1(&"token_suite.aleo".into());
+        let analysis = ProgramAnalysis::analyze(programs, Some(deployed)).unwrap();
+        assert_eq!(strings(&analysis.deployment_order().unwrap()), vec!["vault_suite.aleo", "exchange_suite.aleo"]);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_find_cycles() {
+        let id = |name: &str| ProgramIDNative::from_str(name).unwrap();
+        let mut imports = IndexMap::new();
+        imports.insert(id("a.aleo"), vec![id("b.aleo")]);
+        imports.insert(id("b.aleo"), vec![id("c.aleo"), id("credits.aleo")]);
+        imports.insert(id("c.aleo"), vec![id("a.aleo")]);
+        imports.insert(id("d.aleo"), vec![id("d.aleo")]);
+        imports.insert(id("e.aleo"), vec![id("a.aleo")]);
+        assert_eq!(find_cycles(&imports), vec![vec![id("a.aleo"), id("b.aleo"), id("c.aleo")], vec![id("d.aleo")]]);
+
+        imports.insert(id("c.aleo"), vec![]);
+        imports.insert(id("d.aleo"), vec![]);
+        assert!(find_cycles(&imports).is_empty());
+    }
+}
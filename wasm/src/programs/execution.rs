@@ -15,6 +15,8 @@
 // along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
 
 pub use super::*;
+use crate::{programs::transition::decrypt_transitions, AleoError, ViewKey};
+use js_sys::Array;
 use std::{ops::Deref, str::FromStr};
 use wasm_bindgen::prelude::wasm_bindgen;
 
@@ -43,8 +45,19 @@ impl Execution {
 
     /// Creates an execution object from a string representation of an execution.
     #[wasm_bindgen(js_name = "fromString")]
-    pub fn from_string(execution: &str) -> Result<Execution, String> {
-        Ok(Self(ExecutionNative::from_str(execution).map_err(|e| e.to_string())?))
+    pub fn from_string(execution: &str) -> Result<Execution, AleoError> {
+        Ok(Self(ExecutionNative::from_str(execution).map_err(AleoError::parse)?))
+    }
+
+    /// Decrypt the private inputs and outputs of the transitions in the execution signed by the
+    /// account of a view key. Transitions signed by other accounts are skipped
+    ///
+    /// @param {ViewKey} view_key View key of the account that signed the transitions
+    /// @returns {Array} Array of { id, program, function, inputs, outputs } objects, where inputs
+    /// and outputs hold the { index, value } of each decrypted private value
+    #[wasm_bindgen(js_name = "decryptTransitions")]
+    pub fn decrypt_transitions(&self, view_key: &ViewKey) -> Result<Array, AleoError> {
+        decrypt_transitions(self.0.transitions(), view_key)
     }
 }
 
@@ -83,16 +96,16 @@ pub fn verify_function_execution(
     verifying_key: &VerifyingKey,
     program: &Program,
     function_id: &str,
-) -> Result<bool, String> {
-    let function = IdentifierNative::from_str(function_id).map_err(|e| e.to_string())?;
-    let program_id = ProgramID::<CurrentNetwork>::from_str(&program.id()).unwrap();
-    let mut process = ProcessNative::load_web().map_err(|e| e.to_string())?;
+) -> Result<bool, AleoError> {
+    let function = IdentifierNative::from_str(function_id).map_err(AleoError::parse)?;
+    let program_id = ProgramID::<CurrentNetwork>::from_str(&program.id()).map_err(AleoError::parse)?;
+    let mut process = ProcessNative::load_web().map_err(AleoError::proving)?;
     if &program.id() != "credits.aleo" {
-        process.add_program(program).map_err(|e| e.to_string())?;
+        process.add_program(program).map_err(AleoError::invalid_input)?;
     }
     process
         .insert_verifying_key(&program_id, &function, VerifyingKeyNative::from(verifying_key))
-        .map_err(|e| e.to_string())?;
+        .map_err(AleoError::invalid_input)?;
     process.verify_execution(execution).map_or(Ok(false), |_| Ok(true))
 }
 
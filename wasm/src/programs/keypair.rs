@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{ProvingKey, VerifyingKey};
+use crate::{AleoError, ProvingKey, VerifyingKey};
 
 use crate::types::native::{ProvingKeyNative, VerifyingKeyNative};
 use wasm_bindgen::prelude::wasm_bindgen;
@@ -43,16 +43,16 @@ impl KeyPair {
     ///
     /// @returns {ProvingKey}
     #[wasm_bindgen(js_name = "provingKey")]
-    pub fn proving_key(&mut self) -> Result<ProvingKey, String> {
-        self.proving_key.take().ok_or("Proving key has already been removed".to_string())
+    pub fn proving_key(&mut self) -> Result<ProvingKey, AleoError> {
+        self.proving_key.take().ok_or_else(|| AleoError::missing_key("Proving key has already been removed"))
     }
 
     /// Get the verifying key. This method will remove the verifying key from the key pair
     ///
     /// @returns {VerifyingKey}
     #[wasm_bindgen(js_name = "verifyingKey")]
-    pub fn verifying_key(&mut self) -> Result<VerifyingKey, String> {
-        self.verifying_key.take().ok_or("Proving key has already been removed".to_string())
+    pub fn verifying_key(&mut self) -> Result<VerifyingKey, AleoError> {
+        self.verifying_key.take().ok_or_else(|| AleoError::missing_key("Verifying key has already been removed"))
     }
 }
 
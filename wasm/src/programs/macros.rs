@@ -23,11 +23,18 @@ macro_rules! process_inputs {
             if let Some(input) = input.as_string() {
                 inputs_native.push(input);
             } else {
-                return Err("Invalid input - all inputs must be a string specifying the type".to_string());
+                return Err($crate::AleoError::parse(
+                    "Invalid input - all inputs must be a string specifying the type",
+                )
+                .into());
             }
         }
         inputs_native
     }};
+    ($program:expr, $function:expr, $inputs:expr) => {{
+        log("encoding inputs");
+        $crate::Program::encode_input_values(&$program, $function, &$inputs.to_vec())?
+    }};
 }
 
 #[macro_export]
@@ -36,18 +43,18 @@ macro_rules! execute_program {
         if (($proving_key.is_some() && $verifying_key.is_none())
             || ($proving_key.is_none() && $verifying_key.is_some()))
         {
-            return Err(
-                "If specifying a key for a program execution, both the proving and verifying key must be specified"
-                    .to_string(),
-            );
+            return Err($crate::AleoError::missing_key(
+                "If specifying a key for a program execution, both the proving and verifying key must be specified",
+            )
+            .into());
         }
 
         log("Loading program");
-        let program =
-            ProgramNative::from_str($program_string).map_err(|_| "The program ID provided was invalid".to_string())?;
+        let program = ProgramNative::from_str($program_string)
+            .map_err(|_| $crate::AleoError::parse("The program ID provided was invalid"))?;
         log("Loading function");
         let function_name = IdentifierNative::from_str($function_id_string)
-            .map_err(|_| "The function name provided was invalid".to_string())?;
+            .map_err(|_| $crate::AleoError::parse("The function name provided was invalid"))?;
 
         let program_id = program.id().to_string();
 
@@ -55,10 +62,10 @@ macro_rules! execute_program {
             log("Adding program to the process");
             if let Ok(stored_program) = $process.get_program(program.id()) {
                 if stored_program != &program {
-                    return Err("The program provided does not match the program stored in the cache, please clear the cache before proceeding".to_string());
+                    return Err($crate::AleoError::invalid_input("The program provided does not match the program stored in the cache, please clear the cache before proceeding").into());
                 }
             } else {
-                $process.add_program(&program).map_err(|e| e.to_string())?;
+                $process.add_program(&program).map_err($crate::AleoError::invalid_input)?;
             }
         }
 
@@ -69,28 +76,32 @@ macro_rules! execute_program {
                 log(&format!("Inserting externally provided proving and verifying keys for {program_id} - {function_name:?}"));
                 $process
                     .insert_proving_key(program.id(), &function_name, ProvingKeyNative::from(proving_key))
-                    .map_err(|e| e.to_string())?;
+                    .map_err($crate::AleoError::invalid_input)?;
                 if let Some(verifying_key) = $verifying_key {
-                    $process.insert_verifying_key(program.id(), &function_name, VerifyingKeyNative::from(verifying_key)).map_err(|e| e.to_string())?;
+                    $process.insert_verifying_key(program.id(), &function_name, VerifyingKeyNative::from(verifying_key)).map_err($crate::AleoError::invalid_input)?;
                 }
             }
         };
 
+        log("Validating inputs");
+        let inputs = $inputs;
+        Self::validate_inputs($process, &program, &function_name, &inputs)?;
+
         log("Creating authorization");
         let authorization = $process
             .authorize::<CurrentAleo, _>(
                 $private_key,
                 program.id(),
                 function_name,
-                $inputs.iter(),
+                inputs.iter(),
                 $rng,
             )
-            .map_err(|err| err.to_string())?;
+            .map_err($crate::AleoError::proving)?;
 
         log("Executing program");
         let result = $process
             .execute::<CurrentAleo, _>(authorization, $rng)
-            .map_err(|err| err.to_string())?;
+            .map_err($crate::AleoError::proving)?;
 
         result
     }};
@@ -102,10 +113,10 @@ macro_rules! execute_fee {
         if (($fee_proving_key.is_some() && $fee_verifying_key.is_none())
             || ($fee_proving_key.is_none() && $fee_verifying_key.is_some()))
         {
-            return Err(
-                 "Missing key - both the proving and verifying key must be specified for a program execution"
-                    .to_string(),
-            );
+            return Err($crate::AleoError::missing_key(
+                "Missing key - both the proving and verifying key must be specified for a program execution",
+            )
+            .into());
         }
 
         if let Some(fee_proving_key) = $fee_proving_key {
@@ -120,11 +131,11 @@ macro_rules! execute_fee {
             } else {
                 log("Inserting externally provided fee proving and verifying keys");
                 $process
-                    .insert_proving_key(&credits, &fee, ProvingKeyNative::from(fee_proving_key)).map_err(|e| e.to_string())?;
+                    .insert_proving_key(&credits, &fee, ProvingKeyNative::from(fee_proving_key)).map_err($crate::AleoError::invalid_input)?;
                 if let Some(fee_verifying_key) = $fee_verifying_key {
                     $process
                         .insert_verifying_key(&credits, &fee, VerifyingKeyNative::from(fee_verifying_key))
-                        .map_err(|e| e.to_string())?;
+                        .map_err($crate::AleoError::invalid_input)?;
                 }
             }
         };
@@ -140,28 +151,28 @@ macro_rules! execute_fee {
                     0u64,
                     $execution_id,
                     $rng,
-                ).map_err(|e| e.to_string())?
+                ).map_err($crate::AleoError::proving)?
             }
             None => {
-                $process.authorize_fee_public::<CurrentAleo, _>($private_key, $fee_microcredits, 0u64, $execution_id, $rng).map_err(|e| e.to_string())?
+                $process.authorize_fee_public::<CurrentAleo, _>($private_key, $fee_microcredits, 0u64, $execution_id, $rng).map_err($crate::AleoError::proving)?
             }
         };
 
         log("Executing fee");
         let (_, mut trace) = $process
             .execute::<CurrentAleo, _>(fee_authorization, $rng)
-            .map_err(|err| err.to_string())?;
+            .map_err($crate::AleoError::proving)?;
 
         if let Some(offline_query) = $offline_query {
-            trace.prepare_async(offline_query).await.map_err(|err| err.to_string())?;
+            trace.prepare_async(offline_query).await.map_err($crate::AleoError::query)?;
         } else {
             let query = QueryNative::from($submission_url);
-            trace.prepare_async(query).await.map_err(|err| err.to_string())?;
+            trace.prepare_async(query).await.map_err($crate::AleoError::query)?;
         };
-        let fee = trace.prove_fee::<CurrentAleo, _>(&mut StdRng::from_entropy()).map_err(|e|e.to_string())?;
+        let fee = trace.prove_fee::<CurrentAleo, _>(&mut StdRng::from_entropy()).map_err($crate::AleoError::proving)?;
 
         log("Verifying fee execution");
-        $process.verify_fee(&fee, $execution_id).map_err(|e| e.to_string())?;
+        $process.verify_fee(&fee, $execution_id).map_err($crate::AleoError::proving)?;
 
         fee
     }};
@@ -63,7 +63,7 @@ impl ProgramManager {
         fee_proving_key: Option<ProvingKey>,
         fee_verifying_key: Option<VerifyingKey>,
         offline_query: Option<OfflineQuery>,
-    ) -> Result<Transaction, String> {
+    ) -> Result<Transaction, AleoError> {
         log("Creating deployment transaction");
         // Convert fee to microcredits and check that the fee record has enough credits to pay it
         let fee_microcredits = match &fee_record {
@@ -71,11 +71,11 @@ impl ProgramManager {
             None => (fee_credits * 1_000_000.0) as u64,
         };
 
-        let mut process_native = ProcessNative::load_web().map_err(|err| err.to_string())?;
+        let mut process_native = ProcessNative::load_web().map_err(AleoError::proving)?;
         let process = &mut process_native;
 
         log("Checking program has a valid name");
-        let program = ProgramNative::from_str(program).map_err(|err| err.to_string())?;
+        let program = ProgramNative::from_str(program).map_err(AleoError::parse)?;
 
         log("Checking program imports are valid and add them to the process");
         ProgramManager::resolve_imports(process, &program, imports)?;
@@ -83,22 +83,22 @@ impl ProgramManager {
 
         log("Creating deployment");
         let node_url = url.as_deref().unwrap_or(DEFAULT_URL);
-        let deployment = process.deploy::<CurrentAleo, _>(&program, rng).map_err(|err| err.to_string())?;
+        let deployment = process.deploy::<CurrentAleo, _>(&program, rng).map_err(AleoError::proving)?;
         if deployment.program().functions().is_empty() {
-            return Err("Attempted to create an empty transaction deployment".to_string());
+            return Err(AleoError::invalid_input("Attempted to create an empty transaction deployment"));
         }
 
         log("Ensuring the fee is sufficient to pay for the deployment");
         let (minimum_deployment_cost, (_, _, _)) =
-            deployment_cost::<CurrentNetwork>(&deployment).map_err(|err| err.to_string())?;
+            deployment_cost::<CurrentNetwork>(&deployment).map_err(AleoError::invalid_input)?;
         if fee_microcredits < minimum_deployment_cost {
-            return Err(format!(
+            return Err(AleoError::invalid_input(format!(
                 "Fee is too low to pay for the deployment. The minimum fee is {} credits",
                 minimum_deployment_cost as f64 / 1_000_000.0
-            ));
+            )));
         }
 
-        let deployment_id = deployment.to_deployment_id().map_err(|e| e.to_string())?;
+        let deployment_id = deployment.to_deployment_id().map_err(AleoError::proving)?;
 
         let fee = execute_fee!(
             process,
@@ -115,16 +115,16 @@ impl ProgramManager {
 
         // Create the program owner
         let owner = ProgramOwnerNative::new(private_key, deployment_id, &mut StdRng::from_entropy())
-            .map_err(|err| err.to_string())?;
+            .map_err(AleoError::proving)?;
 
         log("Verifying the deployment and fees");
         process
             .verify_deployment::<CurrentAleo, _>(&deployment, &mut StdRng::from_entropy())
-            .map_err(|err| err.to_string())?;
+            .map_err(AleoError::proving)?;
 
         log("Creating deployment transaction");
         Ok(Transaction::from(
-            TransactionNative::from_deployment(owner, deployment, fee).map_err(|err| err.to_string())?,
+            TransactionNative::from_deployment(owner, deployment, fee).map_err(AleoError::invalid_input)?,
         ))
     }
 
@@ -138,29 +138,29 @@ impl ProgramManager {
     /// are a string representing the program source code \{ "hello.aleo": "hello.aleo source code" \}
     /// @returns {u64}
     #[wasm_bindgen(js_name = estimateDeploymentFee)]
-    pub async fn estimate_deployment_fee(program: &str, imports: Option<Object>) -> Result<u64, String> {
+    pub async fn estimate_deployment_fee(program: &str, imports: Option<Object>) -> Result<u64, AleoError> {
         log(
             "Disclaimer: Fee estimation is experimental and may not represent a correct estimate on any current or future network",
         );
-        let mut process_native = ProcessNative::load_web().map_err(|err| err.to_string())?;
+        let mut process_native = ProcessNative::load_web().map_err(AleoError::proving)?;
         let process = &mut process_native;
 
         log("Check program has a valid name");
-        let program = ProgramNative::from_str(program).map_err(|err| err.to_string())?;
+        let program = ProgramNative::from_str(program).map_err(AleoError::parse)?;
 
         log("Check program imports are valid and add them to the process");
         ProgramManager::resolve_imports(process, &program, imports)?;
 
         log("Create sample deployment");
         let deployment =
-            process.deploy::<CurrentAleo, _>(&program, &mut StdRng::from_entropy()).map_err(|err| err.to_string())?;
+            process.deploy::<CurrentAleo, _>(&program, &mut StdRng::from_entropy()).map_err(AleoError::proving)?;
         if deployment.program().functions().is_empty() {
-            return Err("Attempted to create an empty transaction deployment".to_string());
+            return Err(AleoError::invalid_input("Attempted to create an empty transaction deployment"));
         }
 
         log("Estimate the deployment fee");
         let (minimum_deployment_cost, (_, _, _)) =
-            deployment_cost::<CurrentNetwork>(&deployment).map_err(|err| err.to_string())?;
+            deployment_cost::<CurrentNetwork>(&deployment).map_err(AleoError::invalid_input)?;
 
         Ok(minimum_deployment_cost)
     }
@@ -174,14 +174,14 @@ impl ProgramManager {
     /// @param name The name of the program to be deployed
     /// @returns {u64}
     #[wasm_bindgen(js_name = estimateProgramNameCost)]
-    pub fn program_name_cost(name: &str) -> Result<u64, String> {
+    pub fn program_name_cost(name: &str) -> Result<u64, AleoError> {
         log(
             "Disclaimer: Fee estimation is experimental and may not represent a correct estimate on any current or future network",
         );
         let num_characters = name.chars().count() as u32;
         let namespace_cost = 10u64
             .checked_pow(10u32.saturating_sub(num_characters))
-            .ok_or("The namespace cost computation overflowed for a deployment")?
+            .ok_or_else(|| AleoError::invalid_input("The namespace cost computation overflowed for a deployment"))?
             .saturating_mul(1_000_000); // 1 microcredit = 1e-6 credits.
         Ok(namespace_cost)
     }
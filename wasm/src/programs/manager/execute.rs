@@ -48,7 +48,8 @@ impl ProgramManager {
     /// @param {PrivateKey} private_key The private key of the sender
     /// @param {string} program The source code of the program being executed
     /// @param {string} function The name of the function to execute
-    /// @param {Array} inputs A javascript array of inputs to the function
+    /// @param {Array} inputs A javascript array of inputs to the function. Inputs may be given as
+    /// input strings or as javascript values, which are encoded with `Program.encodeInputs`
     /// @param {boolean} prove_execution If true, the execution will be proven and an execution object
     /// containing the proof and the encrypted inputs and outputs needed to verify the proof offline
     /// will be returned.
@@ -75,22 +76,22 @@ impl ProgramManager {
         verifying_key: Option<VerifyingKey>,
         url: Option<String>,
         offline_query: Option<OfflineQuery>,
-    ) -> Result<ExecutionResponse, String> {
+    ) -> Result<ExecutionResponse, AleoError> {
         log(&format!("Executing local function: {function}"));
         let node_url = url.as_deref().unwrap_or(DEFAULT_URL);
         let inputs = inputs.to_vec();
         let rng = &mut StdRng::from_entropy();
 
-        let mut process_native = ProcessNative::load_web().map_err(|err| err.to_string())?;
+        let mut process_native = ProcessNative::load_web().map_err(AleoError::proving)?;
         let process = &mut process_native;
 
         log("Check program imports are valid and add them to the process");
-        let program_native = ProgramNative::from_str(program).map_err(|e| e.to_string())?;
+        let program_native = ProgramNative::from_str(program).map_err(AleoError::parse)?;
         ProgramManager::resolve_imports(process, &program_native, imports)?;
 
         let (response, mut trace) = execute_program!(
             process,
-            process_inputs!(inputs),
+            process_inputs!(program_native, function, inputs),
             program,
             function,
             private_key,
@@ -102,15 +103,15 @@ impl ProgramManager {
         let mut execution_response = if prove_execution {
             log("Preparing inclusion proofs for execution");
             if let Some(offline_query) = offline_query {
-                trace.prepare_async(offline_query).await.map_err(|err| err.to_string())?;
+                trace.prepare_async(offline_query).await.map_err(AleoError::query)?;
             } else {
                 let query = QueryNative::from(node_url);
-                trace.prepare_async(query).await.map_err(|err| err.to_string())?;
+                trace.prepare_async(query).await.map_err(AleoError::query)?;
             }
 
             log("Proving execution");
             let locator = program_native.id().to_string().add("/").add(function);
-            let execution = trace.prove_execution::<CurrentAleo, _>(&locator, rng).map_err(|e| e.to_string())?;
+            let execution = trace.prove_execution::<CurrentAleo, _>(&locator, rng).map_err(AleoError::proving)?;
             ExecutionResponse::new(Some(execution), function, response, process, program)?
         } else {
             ExecutionResponse::new(None, function, response, process, program)?
@@ -128,7 +129,8 @@ impl ProgramManager {
     /// @param private_key The private key of the sender
     /// @param program The source code of the program being executed
     /// @param function The name of the function to execute
-    /// @param inputs A javascript array of inputs to the function
+    /// @param inputs A javascript array of inputs to the function. Inputs may be given as input
+    /// strings or as javascript values, which are encoded with `Program.encodeInputs`
     /// @param fee_credits The amount of credits to pay as a fee
     /// @param fee_record The record to spend the fee from
     /// @param url The url of the Aleo network node to send the transaction to
@@ -160,25 +162,25 @@ impl ProgramManager {
         fee_proving_key: Option<ProvingKey>,
         fee_verifying_key: Option<VerifyingKey>,
         offline_query: Option<OfflineQuery>,
-    ) -> Result<Transaction, String> {
+    ) -> Result<Transaction, AleoError> {
         log(&format!("Executing function: {function} on-chain"));
         let fee_microcredits = match &fee_record {
             Some(fee_record) => Self::validate_amount(fee_credits, fee_record, true)?,
             None => (fee_credits * 1_000_000.0) as u64,
         };
-        let mut process_native = ProcessNative::load_web().map_err(|err| err.to_string())?;
+        let mut process_native = ProcessNative::load_web().map_err(AleoError::proving)?;
         let process = &mut process_native;
         let node_url = url.as_deref().unwrap_or(DEFAULT_URL);
 
         log("Check program imports are valid and add them to the process");
-        let program_native = ProgramNative::from_str(program).map_err(|e| e.to_string())?;
+        let program_native = ProgramNative::from_str(program).map_err(AleoError::parse)?;
         ProgramManager::resolve_imports(process, &program_native, imports)?;
         let rng = &mut StdRng::from_entropy();
 
         log("Executing program");
         let (_, mut trace) = execute_program!(
             process,
-            process_inputs!(inputs),
+            process_inputs!(program_native, function, inputs),
             program,
             function,
             private_key,
@@ -189,19 +191,19 @@ impl ProgramManager {
 
         log("Preparing inclusion proofs for execution");
         if let Some(offline_query) = offline_query.as_ref() {
-            trace.prepare_async(offline_query.clone()).await.map_err(|err| err.to_string())?;
+            trace.prepare_async(offline_query.clone()).await.map_err(AleoError::query)?;
         } else {
             let query = QueryNative::from(node_url);
-            trace.prepare_async(query).await.map_err(|err| err.to_string())?;
+            trace.prepare_async(query).await.map_err(AleoError::query)?;
         }
 
         log("Proving execution");
-        let program = ProgramNative::from_str(program).map_err(|err| err.to_string())?;
+        let program = ProgramNative::from_str(program).map_err(AleoError::parse)?;
         let locator = program.id().to_string().add("/").add(function);
         let execution = trace
             .prove_execution::<CurrentAleo, _>(&locator, &mut StdRng::from_entropy())
-            .map_err(|e| e.to_string())?;
-        let execution_id = execution.to_execution_id().map_err(|e| e.to_string())?;
+            .map_err(AleoError::proving)?;
+        let execution_id = execution.to_execution_id().map_err(AleoError::proving)?;
 
         log("Executing fee");
         let fee = execute_fee!(
@@ -218,10 +220,10 @@ impl ProgramManager {
         );
 
         // Verify the execution
-        process.verify_execution(&execution).map_err(|err| err.to_string())?;
+        process.verify_execution(&execution).map_err(AleoError::proving)?;
 
         log("Creating execution transaction");
-        let transaction = TransactionNative::from_execution(execution, Some(fee)).map_err(|err| err.to_string())?;
+        let transaction = TransactionNative::from_execution(execution, Some(fee)).map_err(AleoError::invalid_input)?;
         Ok(Transaction::from(transaction))
     }
 
@@ -234,7 +236,8 @@ impl ProgramManager {
     /// @param private_key The private key of the sender
     /// @param program The source code of the program to estimate the execution fee for
     /// @param function The name of the function to execute
-    /// @param inputs A javascript array of inputs to the function
+    /// @param inputs A javascript array of inputs to the function. Inputs may be given as input
+    /// strings or as javascript values, which are encoded with `Program.encodeInputs`
     /// @param url The url of the Aleo network node to send the transaction to
     /// @param imports (optional) Provide a list of imports to use for the fee estimation in the
     /// form of a javascript object where the keys are a string of the program name and the values
@@ -254,24 +257,24 @@ impl ProgramManager {
         proving_key: Option<ProvingKey>,
         verifying_key: Option<VerifyingKey>,
         offline_query: Option<OfflineQuery>,
-    ) -> Result<u64, String> {
+    ) -> Result<u64, AleoError> {
         log(
             "Disclaimer: Fee estimation is experimental and may not represent a correct estimate on any current or future network",
         );
         log(&format!("Executing local function: {function}"));
 
-        let mut process_native = ProcessNative::load_web().map_err(|err| err.to_string())?;
+        let mut process_native = ProcessNative::load_web().map_err(AleoError::proving)?;
         let process = &mut process_native;
 
         log("Check program imports are valid and add them to the process");
-        let program_native = ProgramNative::from_str(program).map_err(|e| e.to_string())?;
+        let program_native = ProgramNative::from_str(program).map_err(AleoError::parse)?;
         ProgramManager::resolve_imports(process, &program_native, imports)?;
         let rng = &mut StdRng::from_entropy();
 
         log("Generating execution trace");
         let (_, mut trace) = execute_program!(
             process,
-            process_inputs!(inputs),
+            process_inputs!(program_native, function, inputs),
             program,
             function,
             private_key,
@@ -282,19 +285,19 @@ impl ProgramManager {
 
         // Execute the program
         let node_url = url.as_deref().unwrap_or(DEFAULT_URL);
-        let program = ProgramNative::from_str(program).map_err(|err| err.to_string())?;
+        let program = ProgramNative::from_str(program).map_err(AleoError::parse)?;
         let locator = program.id().to_string().add("/").add(function);
         if let Some(offline_query) = offline_query {
-            trace.prepare_async(offline_query).await.map_err(|err| err.to_string())?;
+            trace.prepare_async(offline_query).await.map_err(AleoError::query)?;
         } else {
             let query = QueryNative::from(node_url);
-            trace.prepare_async(query).await.map_err(|err| err.to_string())?;
+            trace.prepare_async(query).await.map_err(AleoError::query)?;
         }
-        let execution = trace.prove_execution::<CurrentAleo, _>(&locator, rng).map_err(|e| e.to_string())?;
+        let execution = trace.prove_execution::<CurrentAleo, _>(&locator, rng).map_err(AleoError::proving)?;
 
         // Get the storage cost in bytes for the program execution
         log("Estimating cost");
-        let storage_cost = execution.size_in_bytes().map_err(|e| e.to_string())?;
+        let storage_cost = execution.size_in_bytes().map_err(AleoError::invalid_input)?;
 
         // Compute the finalize cost in microcredits.
         let mut finalize_cost = 0u64;
@@ -303,15 +306,17 @@ impl ProgramManager {
             // Retrieve the function name, program id, and program.
             let function_name = transition.function_name();
             let program_id = transition.program_id();
-            let stack = process.get_stack(program_id).map_err(|e| e.to_string())?;
+            let stack = process.get_stack(program_id).map_err(|e| {
+                AleoError::invalid_input(format!("Program {program_id} is not loaded into the process: {e}"))
+            })?;
 
             // Calculate the finalize cost for the function identified in the transition
-            let cost = cost_in_microcredits(stack, function_name).map_err(|e| e.to_string())?;
+            let cost = cost_in_microcredits(stack, function_name).map_err(AleoError::invalid_input)?;
 
             // Accumulate the finalize cost.
             finalize_cost = finalize_cost
                 .checked_add(cost)
-                .ok_or("The finalize cost computation overflowed for an execution".to_string())?;
+                .ok_or_else(|| AleoError::invalid_input("The finalize cost computation overflowed for an execution"))?;
         }
         Ok(storage_cost + finalize_cost)
     }
@@ -326,19 +331,21 @@ impl ProgramManager {
     /// @param function The function to estimate the finalize fee for
     /// @returns {u64} Fee in microcredits
     #[wasm_bindgen(js_name = estimateFinalizeFee)]
-    pub fn estimate_finalize_fee(program: &str, function: &str) -> Result<u64, String> {
+    pub fn estimate_finalize_fee(program: &str, function: &str) -> Result<u64, AleoError> {
         log(
             "Disclaimer: Fee estimation is experimental and may not represent a correct estimate on any current or future network",
         );
 
-        let mut process_native = ProcessNative::load_web().map_err(|err| err.to_string())?;
+        let mut process_native = ProcessNative::load_web().map_err(AleoError::proving)?;
         let process = &mut process_native;
 
-        let program = ProgramNative::from_str(program).map_err(|err| err.to_string())?;
-        let function_id = IdentifierNative::from_str(function).map_err(|err| err.to_string())?;
+        let program = ProgramNative::from_str(program).map_err(AleoError::parse)?;
+        let function_id = IdentifierNative::from_str(function).map_err(AleoError::parse)?;
 
-        let stack = process.get_stack(program.id()).map_err(|e| e.to_string())?;
+        let stack = process.get_stack(program.id()).map_err(|e| {
+            AleoError::invalid_input(format!("Program {} is not loaded into the process: {e}", program.id()))
+        })?;
 
-        cost_in_microcredits(stack, &function_id).map_err(|e| e.to_string())
+        cost_in_microcredits(stack, &function_id).map_err(AleoError::invalid_input)
     }
 }
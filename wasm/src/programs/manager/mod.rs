@@ -22,7 +22,7 @@ pub mod transfer;
 
 const DEFAULT_URL: &str = "https://api.explorer.provable.com/v1";
 
-use crate::{KeyPair, PrivateKey, ProvingKey, RecordPlaintext, VerifyingKey};
+use crate::{AleoError, Balance, BalanceSpec, KeyPair, PrivateKey, ProvingKey, RecordPlaintext, VerifyingKey};
 
 use crate::types::native::{
     cost_in_microcredits,
@@ -33,6 +33,8 @@ use crate::types::native::{
     ProgramNative,
     ProvingKeyNative,
     QueryNative,
+    StackMatches,
+    ValueNative,
     VerifyingKeyNative,
 };
 use js_sys::{Object, Reflect};
@@ -47,20 +49,56 @@ pub struct ProgramManager;
 impl ProgramManager {
     /// Validate that an amount being paid from a record is greater than zero and that the record
     /// has enough credits to pay the amount
-    pub(crate) fn validate_amount(credits: f64, amount: &RecordPlaintext, fee: bool) -> Result<u64, String> {
+    pub(crate) fn validate_amount(credits: f64, amount: &RecordPlaintext, fee: bool) -> Result<u64, AleoError> {
         let name = if fee { "Fee" } else { "Amount" };
 
         if credits <= 0.0 {
-            return Err(format!("{name} must be greater than zero to deploy or execute a program"));
+            return Err(AleoError::invalid_input(format!(
+                "{name} must be greater than zero to deploy or execute a program"
+            )));
         }
         let microcredits = (credits * 1_000_000.0f64) as u64;
-        if amount.microcredits() < microcredits {
-            return Err(format!("{name} record does not have enough credits to pay the specified fee"));
-        }
+        Self::validate_balance(microcredits as u128, amount, &BalanceSpec::credits(), name)?;
 
         Ok(microcredits)
     }
 
+    /// Validate that an amount being paid from a record of any program is greater than zero and
+    /// that the balance of the record described by the balance spec covers the amount
+    pub(crate) fn validate_balance(
+        amount: u128,
+        record: &RecordPlaintext,
+        spec: &BalanceSpec,
+        name: &str,
+    ) -> Result<(), AleoError> {
+        if amount == 0 {
+            return Err(AleoError::invalid_input(format!("{name} must be greater than zero")));
+        }
+        let balance = Balance::balance(&**record, spec)?;
+        if balance < amount {
+            return Err(AleoError::insufficient_balance(format!(
+                "{name} record does not have enough balance to pay the specified amount"
+            )));
+        }
+        Ok(())
+    }
+
+    /// Validate that a record of any program, such as a token record, holds enough balance to pay
+    /// an amount
+    ///
+    /// @param {RecordPlaintext} record Record paying the amount
+    /// @param {string} amount Amount to pay as a decimal integer
+    /// @param {BalanceSpec} spec Balance spec of the record
+    #[wasm_bindgen(js_name = "validateBalance")]
+    pub fn validate_record_balance(
+        record: &RecordPlaintext,
+        amount: &str,
+        spec: &BalanceSpec,
+    ) -> Result<(), AleoError> {
+        let amount = amount.parse::<u128>().map_err(|_| AleoError::parse(format!("{amount} is an invalid amount")))?;
+        Self::validate_balance(amount, record, spec, "Amount")
+    }
+
     /// Synthesize proving and verifying keys for a program
     ///
     /// @param program {string} The program source code of the program to synthesize keys for
@@ -74,7 +112,7 @@ impl ProgramManager {
         function_id: &str,
         inputs: js_sys::Array,
         imports: Option<Object>,
-    ) -> Result<KeyPair, String> {
+    ) -> Result<KeyPair, AleoError> {
         ProgramManager::execute_function_offline(
             private_key,
             program,
@@ -90,6 +128,7 @@ impl ProgramManager {
         )
         .await?
         .get_keys()
+        .map_err(AleoError::from)
     }
 
     /// Check if a process contains a keypair for a specific function
@@ -104,32 +143,71 @@ impl ProgramManager {
         )
     }
 
+    /// Check that the inputs of a function match its signature before authorizing and proving it,
+    /// so type mismatches are reported with the input they occur in rather than deep in the prover
+    pub(crate) fn validate_inputs(
+        process: &ProcessNative,
+        program: &ProgramNative,
+        function_id: &IdentifierNative,
+        inputs: &[String],
+    ) -> Result<(), AleoError> {
+        let function = program
+            .functions()
+            .get(function_id)
+            .ok_or_else(|| AleoError::invalid_input(format!("Function {function_id} not found in {}", program.id())))?;
+        if inputs.len() != function.inputs().len() {
+            return Err(AleoError::invalid_input(format!(
+                "Function {function_id} expects {} inputs, but {} were provided",
+                function.inputs().len(),
+                inputs.len()
+            )));
+        }
+        let stack = process.get_stack(program.id()).map_err(|e| {
+            AleoError::invalid_input(format!("Program {} is not loaded into the process: {e}", program.id()))
+        })?;
+        for (index, (input, declaration)) in inputs.iter().zip(function.inputs().iter()).enumerate() {
+            let register = declaration.register();
+            let value_type = declaration.value_type();
+            let value = ValueNative::from_str(input).map_err(|_| {
+                AleoError::invalid_input(format!(
+                    "Input {index} ({register}) could not be parsed, expected {value_type}"
+                ))
+            })?;
+            stack.matches_value_type(&value, value_type).map_err(|e| {
+                AleoError::invalid_input(format!(
+                    "Input {index} ({register}) does not match the expected type {value_type}: {e}"
+                ))
+            })?;
+        }
+        Ok(())
+    }
+
     /// Resolve imports for a program in depth first search order
     pub(crate) fn resolve_imports(
         process: &mut ProcessNative,
         program: &ProgramNative,
         imports: Option<Object>,
-    ) -> Result<(), String> {
+    ) -> Result<(), AleoError> {
         if let Some(imports) = imports {
             program.imports().keys().try_for_each(|program_id| {
                 // Get the program string
                 let program_id = program_id.to_string();
                 if let Some(import_string) = Reflect::get(&imports, &program_id.as_str().into())
-                    .map_err(|_| "Program import not found in imports provided".to_string())?
+                    .map_err(|_| AleoError::invalid_input("Program import not found in imports provided"))?
                     .as_string()
                 {
                     if &program_id != "credits.aleo" {
                         crate::log(&format!("Importing program: {}", program_id));
-                        let import = ProgramNative::from_str(&import_string).map_err(|err| err.to_string())?;
+                        let import = ProgramNative::from_str(&import_string).map_err(AleoError::parse)?;
                         // If the program has imports, add them
                         Self::resolve_imports(process, &import, Some(imports.clone()))?;
                         // If the process does not already contain the program, add it
                         if !process.contains_program(import.id()) {
-                            process.add_program(&import).map_err(|err| err.to_string())?;
+                            process.add_program(&import).map_err(AleoError::invalid_input)?;
                         }
                     }
                 }
-                Ok::<(), String>(())
+                Ok::<(), AleoError>(())
             })
         } else {
             Ok(())
@@ -217,4 +295,75 @@ function add_and_double:
         assert_eq!(double_import, &double_program);
         assert!(main_program.is_err());
     }
+
+    #[wasm_bindgen_test]
+    fn test_validate_amount_error_codes() {
+        let record = RecordPlaintext::from_string(
+            "{ owner: aleo1j7qxyunfldj2lp8hsvy7mw5k8zaqgjfyr72x2gh3x4ewgae8v5gscf5jh3.private, microcredits: 1000000u64.private, _nonce: 3077450429259593211617823051143573281856129402760267155982965992208217472983group.public }",
+        )
+        .unwrap();
+        assert_eq!(ProgramManager::validate_amount(0.5, &record, true).unwrap(), 500000);
+        assert_eq!(
+            ProgramManager::validate_amount(0.0, &record, true).unwrap_err().code(),
+            crate::ErrorCode::InvalidInput
+        );
+        assert_eq!(
+            ProgramManager::validate_amount(2.0, &record, true).unwrap_err().code(),
+            crate::ErrorCode::InsufficientBalance
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn test_validate_token_balance() {
+        let record = RecordPlaintext::from_string(
+            "{ owner: aleo1j7qxyunfldj2lp8hsvy7mw5k8zaqgjfyr72x2gh3x4ewgae8v5gscf5jh3.private, amount: 100u128.private, _nonce: 3077450429259593211617823051143573281856129402760267155982965992208217472983group.public }",
+        )
+        .unwrap();
+        let spec = BalanceSpec::new("token_test.aleo", "token", "amount", "u128").unwrap();
+        assert!(ProgramManager::validate_record_balance(&record, "100", &spec).is_ok());
+        assert_eq!(
+            ProgramManager::validate_record_balance(&record, "101", &spec).unwrap_err().code(),
+            crate::ErrorCode::InsufficientBalance
+        );
+        assert_eq!(
+            ProgramManager::validate_record_balance(&record, "0", &spec).unwrap_err().code(),
+            crate::ErrorCode::InvalidInput
+        );
+        assert_eq!(
+            ProgramManager::validate_amount(0.5, &record, true).unwrap_err().code(),
+            crate::ErrorCode::InvalidInput
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn test_validate_inputs() {
+        let process = ProcessNative::load_web().unwrap();
+        let credits = ProgramNative::credits().unwrap();
+        let transfer_public = IdentifierNative::from_str("transfer_public").unwrap();
+        let recipient = "aleo1j7qxyunfldj2lp8hsvy7mw5k8zaqgjfyr72x2gh3x4ewgae8v5gscf5jh3".to_string();
+
+        let inputs = [recipient.clone(), "5u64".to_string()];
+        assert!(ProgramManager::validate_inputs(&process, &credits, &transfer_public, &inputs).is_ok());
+
+        // Assert mismatched types name the input index, register and expected type
+        let inputs = [recipient.clone(), "5u32".to_string()];
+        let error = ProgramManager::validate_inputs(&process, &credits, &transfer_public, &inputs).unwrap_err();
+        assert_eq!(error.code(), crate::ErrorCode::InvalidInput);
+        assert!(error.message().starts_with("Input 1 (r1)"));
+        assert!(error.message().contains("u64.public"));
+
+        let inputs = [recipient.clone(), "not a value".to_string()];
+        assert!(ProgramManager::validate_inputs(&process, &credits, &transfer_public, &inputs).is_err());
+        assert!(ProgramManager::validate_inputs(&process, &credits, &transfer_public, &[recipient]).is_err());
+
+        // Assert records are checked against the record type of the function
+        let transfer_private = IdentifierNative::from_str("transfer_private").unwrap();
+        let token = "{ owner: aleo1j7qxyunfldj2lp8hsvy7mw5k8zaqgjfyr72x2gh3x4ewgae8v5gscf5jh3.private, amount: 100u64.private, _nonce: 3077450429259593211617823051143573281856129402760267155982965992208217472983group.public }";
+        let inputs = [
+            token.to_string(),
+            "aleo1j7qxyunfldj2lp8hsvy7mw5k8zaqgjfyr72x2gh3x4ewgae8v5gscf5jh3".to_string(),
+            "5u64".to_string(),
+        ];
+        assert!(ProgramManager::validate_inputs(&process, &credits, &transfer_private, &inputs).is_err());
+    }
 }
@@ -45,7 +45,7 @@ impl ProgramManager {
         split_proving_key: Option<ProvingKey>,
         split_verifying_key: Option<VerifyingKey>,
         offline_query: Option<OfflineQuery>,
-    ) -> Result<Transaction, String> {
+    ) -> Result<Transaction, AleoError> {
         log("Executing split program");
         let amount_microcredits = Self::validate_amount(split_amount, &amount_record, false)?;
 
@@ -56,7 +56,7 @@ impl ProgramManager {
         inputs.set(0u32, wasm_bindgen::JsValue::from_str(&amount_record.to_string()));
         inputs.set(1u32, wasm_bindgen::JsValue::from_str(&amount_microcredits.to_string().add("u64")));
 
-        let mut process_native = ProcessNative::load_web().map_err(|err| err.to_string())?;
+        let mut process_native = ProcessNative::load_web().map_err(AleoError::proving)?;
         let process = &mut process_native;
         let rng = &mut StdRng::from_entropy();
 
@@ -74,21 +74,21 @@ impl ProgramManager {
 
         log("Preparing the inclusion proof for the split execution");
         if let Some(offline_query) = offline_query.as_ref() {
-            trace.prepare_async(offline_query.clone()).await.map_err(|err| err.to_string())?;
+            trace.prepare_async(offline_query.clone()).await.map_err(AleoError::query)?;
         } else {
             let query = QueryNative::from(node_url);
-            trace.prepare_async(query).await.map_err(|err| err.to_string())?;
+            trace.prepare_async(query).await.map_err(AleoError::query)?;
         }
 
         log("Proving the split execution");
         let execution =
-            trace.prove_execution::<CurrentAleo, _>("credits.aleo/split", rng).map_err(|e| e.to_string())?;
+            trace.prove_execution::<CurrentAleo, _>("credits.aleo/split", rng).map_err(AleoError::proving)?;
 
         log("Verifying the split execution");
-        process.verify_execution(&execution).map_err(|err| err.to_string())?;
+        process.verify_execution(&execution).map_err(AleoError::proving)?;
 
         log("Creating execution transaction for split");
-        let transaction = TransactionNative::from_execution(execution, None).map_err(|err| err.to_string())?;
+        let transaction = TransactionNative::from_execution(execution, None).map_err(AleoError::invalid_input)?;
         Ok(Transaction::from(transaction))
     }
 }
@@ -72,7 +72,7 @@ impl ProgramManager {
         fee_proving_key: Option<ProvingKey>,
         fee_verifying_key: Option<VerifyingKey>,
         offline_query: Option<OfflineQuery>,
-    ) -> Result<Transaction, String> {
+    ) -> Result<Transaction, AleoError> {
         log("Executing transfer program");
         let fee_microcredits = match &fee_record {
             Some(fee_record) => Self::validate_amount(fee_credits, fee_record, true)?,
@@ -94,7 +94,7 @@ impl ProgramManager {
         let (transfer_type, inputs) = match transfer_type {
             "private" | "transfer_private" | "transferPrivate" => {
                 if amount_record.is_none() {
-                    return Err("Amount record must be provided for private transfers".to_string());
+                    return Err(AleoError::invalid_input("Amount record must be provided for private transfers"));
                 }
                 let inputs = [
                     JsValue::from_str(&amount_record.unwrap().to_string()),
@@ -107,7 +107,7 @@ impl ProgramManager {
             }
             "private_to_public" | "privateToPublic" | "transfer_private_to_public" | "transferPrivateToPublic" => {
                 if amount_record.is_none() {
-                    return Err("Amount record must be provided for private transfers".to_string());
+                    return Err(AleoError::invalid_input("Amount record must be provided for private transfers"));
                 }
                 let inputs = [
                     JsValue::from_str(&amount_record.unwrap().to_string()),
@@ -136,26 +136,28 @@ impl ProgramManager {
                     .collect::<js_sys::Array>();
                 ("transfer_public_to_private", inputs)
             }
-            _ => return Err("Invalid transfer type".to_string()),
+            _ => return Err(AleoError::invalid_input("Invalid transfer type")),
         };
 
-        let mut process_native = ProcessNative::load_web().map_err(|err| err.to_string())?;
+        let mut process_native = ProcessNative::load_web().map_err(AleoError::proving)?;
         let process = &mut process_native;
         let fee_identifier = if fee_record.is_some() {
-            IdentifierNative::from_str("fee_private").map_err(|e| e.to_string())?
+            IdentifierNative::from_str("fee_private").map_err(AleoError::parse)?
         } else {
-            IdentifierNative::from_str("fee_public").map_err(|e| e.to_string())?
+            IdentifierNative::from_str("fee_public").map_err(AleoError::parse)?
         };
-        let stack = process.get_stack("credits.aleo").map_err(|e| e.to_string())?;
+        let stack = process.get_stack("credits.aleo").map_err(|e| {
+            AleoError::invalid_input(format!("Program credits.aleo is not loaded into the process: {e}"))
+        })?;
         if !stack.contains_proving_key(&fee_identifier) && fee_proving_key.is_some() && fee_verifying_key.is_some() {
             let fee_proving_key = fee_proving_key.clone().unwrap();
             let fee_verifying_key = fee_verifying_key.clone().unwrap();
             stack
                 .insert_proving_key(&fee_identifier, ProvingKeyNative::from(fee_proving_key))
-                .map_err(|e| e.to_string())?;
+                .map_err(AleoError::invalid_input)?;
             stack
                 .insert_verifying_key(&fee_identifier, VerifyingKeyNative::from(fee_verifying_key))
-                .map_err(|e| e.to_string())?;
+                .map_err(AleoError::invalid_input)?;
         }
 
         log("Executing transfer function");
@@ -172,19 +174,19 @@ impl ProgramManager {
 
         log("Preparing the inclusion proof for the transfer execution");
         if let Some(offline_query) = offline_query.as_ref() {
-            trace.prepare_async(offline_query.clone()).await.map_err(|err| err.to_string())?;
+            trace.prepare_async(offline_query.clone()).await.map_err(AleoError::query)?;
         } else {
             let query = QueryNative::from(node_url);
-            trace.prepare_async(query).await.map_err(|err| err.to_string())?;
+            trace.prepare_async(query).await.map_err(AleoError::query)?;
         }
 
         log("Proving the transfer execution");
         let execution =
-            trace.prove_execution::<CurrentAleo, _>("credits.aleo/transfer", rng).map_err(|e| e.to_string())?;
-        let execution_id = execution.to_execution_id().map_err(|e| e.to_string())?;
+            trace.prove_execution::<CurrentAleo, _>("credits.aleo/transfer", rng).map_err(AleoError::proving)?;
+        let execution_id = execution.to_execution_id().map_err(AleoError::proving)?;
 
         log("Verifying the transfer execution");
-        process.verify_execution(&execution).map_err(|err| err.to_string())?;
+        process.verify_execution(&execution).map_err(AleoError::proving)?;
 
         log("Executing the fee");
         let fee = execute_fee!(
@@ -201,7 +203,7 @@ impl ProgramManager {
         );
 
         log("Creating execution transaction for transfer");
-        let transaction = TransactionNative::from_execution(execution, Some(fee)).map_err(|err| err.to_string())?;
+        let transaction = TransactionNative::from_execution(execution, Some(fee)).map_err(AleoError::invalid_input)?;
         Ok(Transaction::from(transaction))
     }
 }
@@ -16,6 +16,12 @@
 
 mod macros;
 
+pub mod abi;
+pub use abi::*;
+
+pub mod analysis;
+pub use analysis::*;
+
 pub mod execution;
 pub use execution::*;
 
@@ -42,5 +48,11 @@ pub use response::*;
 pub mod transaction;
 pub use transaction::*;
 
+pub(crate) mod transition;
+
+pub(crate) mod typescript;
+
+pub(crate) mod value_type;
+
 pub mod verifying_key;
 pub use verifying_key::*;
@@ -14,7 +14,10 @@
 // You should have received a copy of the GNU General Public License
 // along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::types::native::{CurrentNetwork, Field, Network};
+use crate::{
+    types::native::{CurrentNetwork, Field, Network},
+    AleoError,
+};
 use snarkvm_console::program::StatePath;
 use snarkvm_ledger_query::QueryTrait;
 
@@ -39,8 +42,8 @@ pub struct OfflineQuery {
 impl OfflineQuery {
     /// Creates a new offline query object. The state root is required to be passed in as a string
     #[wasm_bindgen(constructor)]
-    pub fn new(state_root: &str) -> Result<OfflineQuery, String> {
-        let state_root = <CurrentNetwork as Network>::StateRoot::from_str(state_root).map_err(|e| e.to_string())?;
+    pub fn new(state_root: &str) -> Result<OfflineQuery, AleoError> {
+        let state_root = <CurrentNetwork as Network>::StateRoot::from_str(state_root).map_err(AleoError::parse)?;
         Ok(Self { state_paths: IndexMap::new(), state_root })
     }
 
@@ -49,9 +52,9 @@ impl OfflineQuery {
     /// @param {string} commitment: The commitment corresponding to a record inpout
     /// @param {string} state_path: The state path corresponding to the commitment
     #[wasm_bindgen(js_name = "addStatePath")]
-    pub fn add_state_path(&mut self, commitment: &str, state_path: &str) -> Result<(), String> {
-        let commitment = Field::from_str(commitment).map_err(|e| e.to_string())?;
-        let state_path = StatePath::from_str(state_path).map_err(|e| e.to_string())?;
+    pub fn add_state_path(&mut self, commitment: &str, state_path: &str) -> Result<(), AleoError> {
+        let commitment = Field::from_str(commitment).map_err(AleoError::parse)?;
+        let state_path = StatePath::from_str(state_path).map_err(AleoError::parse)?;
         self.state_paths.insert(commitment, state_path);
         Ok(())
     }
@@ -65,8 +68,8 @@ impl OfflineQuery {
 
     /// Create an offline query object from a json string representation
     #[wasm_bindgen(js_name = "fromString")]
-    pub fn from_string(s: &str) -> Result<OfflineQuery, String> {
-        serde_json::from_str(s).map_err(|e| e.to_string())
+    pub fn from_string(s: &str) -> Result<OfflineQuery, AleoError> {
+        serde_json::from_str(s).map_err(AleoError::parse)
     }
 }
 
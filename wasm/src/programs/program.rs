@@ -14,14 +14,34 @@
 // You should have received a copy of the GNU General Public License
 // along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
 
+use super::{
+    typescript::TypeScriptGenerator,
+    value_type::{DeclaredType, SplitVisibility},
+    ProgramAbi,
+};
 use crate::{
     account::Address,
-    types::native::{CurrentNetwork, EntryType, IdentifierNative, PlaintextType, ProgramNative, ValueType},
+    record::RecordPlaintext,
+    types::native::{
+        CurrentNetwork,
+        IdentifierNative,
+        LiteralNative,
+        PlaintextNative,
+        PlaintextType,
+        ProgramNative,
+        RecordPlaintextNative,
+    },
+    AleoError,
 };
 
-use js_sys::{Array, Object, Reflect};
+use indexmap::IndexMap;
+use js_sys::{Array, BigInt, Object, Reflect};
+use once_cell::sync::OnceCell;
 use std::{ops::Deref, str::FromStr};
-use wasm_bindgen::{prelude::wasm_bindgen, JsValue};
+use wasm_bindgen::{prelude::wasm_bindgen, JsCast, JsValue};
+
+/// Largest integer javascript numbers represent exactly (`Number.MAX_SAFE_INTEGER`)
+const MAX_SAFE_INTEGER: f64 = 9_007_199_254_740_991.0;
 
 /// Webassembly Representation of an Aleo program
 #[wasm_bindgen]
@@ -35,8 +55,8 @@ impl Program {
     /// @param {string} program Aleo program source code
     /// @returns {Program} Program object
     #[wasm_bindgen(js_name = "fromString")]
-    pub fn from_string(program: &str) -> Result<Program, String> {
-        Ok(Self(ProgramNative::from_str(program).map_err(|err| err.to_string())?))
+    pub fn from_string(program: &str) -> Result<Program, AleoError> {
+        Ok(Self(ProgramNative::from_str(program).map_err(AleoError::parse)?))
     }
 
     /// Get a string representation of the program
@@ -124,68 +144,167 @@ impl Program {
     /// const transfer_function_inputs = credits_program.getFunctionInputs("transfer_private");
     /// console.log(transfer_function_inputs === expected_inputs); // Output should be "true"
     #[wasm_bindgen(js_name = "getFunctionInputs")]
-    pub fn get_function_inputs(&self, function_name: String) -> Result<Array, String> {
-        let function_id = IdentifierNative::from_str(&function_name).map_err(|e| e.to_string())?;
-        let function = self
-            .0
-            .functions()
-            .get(&function_id)
-            .ok_or_else(|| format!("function {} not found in {}", function_name, self.0.id()))?;
+    pub fn get_function_inputs(&self, function_name: String) -> Result<Array, AleoError> {
+        let function_id = IdentifierNative::from_str(&function_name).map_err(AleoError::parse)?;
+        let function = self.0.functions().get(&function_id).ok_or_else(|| {
+            AleoError::invalid_input(format!("function {} not found in {}", function_name, self.0.id()))
+        })?;
         let function_inputs = Array::new_with_length(function.inputs().len() as u32);
         for (index, input) in function.inputs().iter().enumerate() {
+            let input_object = self.get_declared_type(input.value_type())?;
             let register = JsValue::from_str(&input.register().to_string());
-            match input.value_type() {
-                ValueType::Constant(plaintext) => {
-                    function_inputs.set(index as u32, {
-                        let input = self.get_plaintext_input(plaintext, Some("constant".to_string()), None)?;
-                        Reflect::set(&input, &"register".into(), &register).map_err(|_| "Failed to set property")?;
-                        input.into()
-                    });
-                }
-                ValueType::Public(plaintext) => {
-                    function_inputs.set(index as u32, {
-                        let input = self.get_plaintext_input(plaintext, Some("public".to_string()), None)?;
-                        Reflect::set(&input, &"register".into(), &register).map_err(|_| "Failed to set property")?;
-                        input.into()
-                    });
-                }
-                ValueType::Private(plaintext) => {
-                    function_inputs.set(index as u32, {
-                        let input = self.get_plaintext_input(plaintext, Some("private".to_string()), None)?;
-                        Reflect::set(&input, &"register".into(), &register).map_err(|_| "Failed to set property")?;
-                        input.into()
-                    });
-                }
-                ValueType::Record(identifier) => {
-                    function_inputs.set(index as u32, {
-                        let input = self.get_record_members(identifier.to_string())?;
-                        Reflect::set(&input, &"register".into(), &register).map_err(|_| "Failed to set property")?;
-                        input.into()
-                    });
-                }
-                ValueType::ExternalRecord(locator) => {
-                    let input = Object::new();
-                    let value_type = JsValue::from_str("external_record");
-                    Reflect::set(&input, &"type".into(), &value_type).map_err(|_| "Failed to set property")?;
-                    Reflect::set(&input, &"locator".into(), &locator.to_string().into())
-                        .map_err(|_| "Failed to set property")?;
-                    Reflect::set(&input, &"register".into(), &register).map_err(|_| "Failed to set property")?;
-                    function_inputs.set(index as u32, input.into());
-                }
-                ValueType::Future(locator) => {
-                    let input = Object::new();
-                    let value_type = JsValue::from_str("future");
-                    Reflect::set(&input, &"type".into(), &value_type).map_err(|_| "Failed to set property")?;
-                    Reflect::set(&input, &"locator".into(), &locator.to_string().into())
-                        .map_err(|_| "Failed to set property")?;
-                    Reflect::set(&input, &"register".into(), &register).map_err(|_| "Failed to set property")?;
-                    function_inputs.set(index as u32, input.into());
-                }
-            }
+            Reflect::set(&input_object, &"register".into(), &register)
+                .map_err(|_| AleoError::serialization("Failed to set property"))?;
+            function_inputs.set(index as u32, input_object.into());
         }
         Ok(function_inputs)
     }
 
+    /// Get a javascript object representation of the function outputs and types, in the same shape
+    /// as the inputs returned by `getFunctionInputs`. The register of an output is the operand it
+    /// is assigned from
+    ///
+    /// @param {string} function_name Name of the function to get outputs for
+    /// @returns {Array} Array of function outputs
+    ///
+    /// @example
+    /// const expected_outputs = [
+    ///     {
+    ///       type:"record",
+    ///       record:"credits",
+    ///       members:[...],
+    ///       register:"r4"
+    ///     },
+    ///     {
+    ///       type:"future",
+    ///       locator:"credits.aleo/transfer_public_to_private",
+    ///       register:"r5"
+    ///     }
+    /// ];
+    ///
+    /// const credits_program = aleo_wasm.Program.getCreditsProgram();
+    /// const outputs = credits_program.getFunctionOutputs("transfer_public_to_private");
+    #[wasm_bindgen(js_name = "getFunctionOutputs")]
+    pub fn get_function_outputs(&self, function_name: String) -> Result<Array, AleoError> {
+        let function_id = IdentifierNative::from_str(&function_name).map_err(AleoError::parse)?;
+        let function = self.0.functions().get(&function_id).ok_or_else(|| {
+            AleoError::invalid_input(format!("function {} not found in {}", function_name, self.0.id()))
+        })?;
+        let function_outputs = Array::new_with_length(function.outputs().len() as u32);
+        for (index, output) in function.outputs().iter().enumerate() {
+            let output_object = self.get_declared_type(output.value_type())?;
+            let register = JsValue::from_str(&output.operand().to_string());
+            Reflect::set(&output_object, &"register".into(), &register)
+                .map_err(|_| AleoError::serialization("Failed to set property"))?;
+            function_outputs.set(index as u32, output_object.into());
+        }
+        Ok(function_outputs)
+    }
+
+    /// Get a javascript array of the closures of the program with their inputs and outputs. Closure
+    /// values have no visibility, so their inputs and outputs carry no visibility property
+    ///
+    /// @returns {Array} Array of closures
+    ///
+    /// @example
+    /// const expected_closures = [
+    ///     {
+    ///       name:"add_closure",
+    ///       inputs:[{ type:"u64", register:"r0" }, { type:"u64", register:"r1" }],
+    ///       outputs:[{ type:"u64", register:"r2" }]
+    ///     }
+    /// ];
+    ///
+    /// const closures = program.getClosures();
+    #[wasm_bindgen(js_name = "getClosures")]
+    pub fn get_closures(&self) -> Result<Array, AleoError> {
+        let closures = Array::new();
+        for closure in self.0.closures().values() {
+            let inputs = Array::new_with_length(closure.inputs().len() as u32);
+            for (index, input) in closure.inputs().iter().enumerate() {
+                let input_object = self.get_declared_type(input.register_type())?;
+                let register = JsValue::from_str(&input.register().to_string());
+                Reflect::set(&input_object, &"register".into(), &register)
+                    .map_err(|_| AleoError::serialization("Failed to set property"))?;
+                inputs.set(index as u32, input_object.into());
+            }
+            let outputs = Array::new_with_length(closure.outputs().len() as u32);
+            for (index, output) in closure.outputs().iter().enumerate() {
+                let output_object = self.get_declared_type(output.register_type())?;
+                let register = JsValue::from_str(&output.operand().to_string());
+                Reflect::set(&output_object, &"register".into(), &register)
+                    .map_err(|_| AleoError::serialization("Failed to set property"))?;
+                outputs.set(index as u32, output_object.into());
+            }
+
+            let closure_object = Object::new();
+            Reflect::set(&closure_object, &"name".into(), &closure.name().to_string().into())
+                .map_err(|_| AleoError::serialization("Failed to set property"))?;
+            Reflect::set(&closure_object, &"inputs".into(), &inputs)
+                .map_err(|_| AleoError::serialization("Failed to set property"))?;
+            Reflect::set(&closure_object, &"outputs".into(), &outputs)
+                .map_err(|_| AleoError::serialization("Failed to set property"))?;
+            closures.push(&closure_object);
+        }
+        Ok(closures)
+    }
+
+    /// Get a javascript object representation of the inputs of the finalize block of a function, in
+    /// the same shape as the inputs returned by `getFunctionInputs`. Finalize inputs are always
+    /// public. The array is empty if the function has no finalize block
+    ///
+    /// @param {string} function_name Name of the function to get finalize inputs for
+    /// @returns {Array} Array of finalize inputs
+    ///
+    /// @example
+    /// const expected_finalize_inputs = [
+    ///     {
+    ///       type:"address",
+    ///       visibility:"public",
+    ///       register:"r0"
+    ///     },
+    ///     {
+    ///       type:"u64",
+    ///       visibility:"public",
+    ///       register:"r1"
+    ///     }
+    /// ];
+    ///
+    /// const credits_program = aleo_wasm.Program.getCreditsProgram();
+    /// const finalize_inputs = credits_program.getFinalizeInputs("transfer_public_to_private");
+    #[wasm_bindgen(js_name = "getFinalizeInputs")]
+    pub fn get_finalize_inputs(&self, function_name: String) -> Result<Array, AleoError> {
+        let function_id = IdentifierNative::from_str(&function_name).map_err(AleoError::parse)?;
+        let function = self.0.functions().get(&function_id).ok_or_else(|| {
+            AleoError::invalid_input(format!("function {} not found in {}", function_name, self.0.id()))
+        })?;
+        let finalize_inputs = Array::new();
+        if let Some(finalize) = function.finalize_logic() {
+            for input in finalize.inputs() {
+                let input_object = self.get_declared_type(input.finalize_type())?;
+                let register = JsValue::from_str(&input.register().to_string());
+                Reflect::set(&input_object, &"register".into(), &register)
+                    .map_err(|_| AleoError::serialization("Failed to set property"))?;
+                finalize_inputs.push(&input_object);
+            }
+        }
+        Ok(finalize_inputs)
+    }
+
+    /// Determine if a function is asynchronous, meaning it has a finalize block which is executed
+    /// on chain after the function is proven
+    ///
+    /// @param {string} function_name Name of the function to check
+    /// @returns {boolean} True if the function has a finalize block, false otherwise
+    #[wasm_bindgen(js_name = "isAsync")]
+    pub fn is_async(&self, function_name: &str) -> Result<bool, AleoError> {
+        let function_id = IdentifierNative::from_str(function_name).map_err(AleoError::parse)?;
+        let function = self.0.functions().get(&function_id).ok_or_else(|| {
+            AleoError::invalid_input(format!("function {} not found in {}", function_name, self.0.id()))
+        })?;
+        Ok(function.finalize_logic().is_some())
+    }
+
     /// Get a the list of a program's mappings and the names/types of their keys and values.
     ///
     /// @returns {Array} - An array of objects representing the mappings in the program
@@ -204,20 +323,20 @@ impl Program {
     /// const credits_mappings = credits_program.getMappings();
     /// console.log(credits_mappings === expected_mappings); // Output should be "true"
     #[wasm_bindgen(js_name = "getMappings")]
-    pub fn get_mappings(&self) -> Result<Array, String> {
+    pub fn get_mappings(&self) -> Result<Array, AleoError> {
         let mappings = Array::new();
 
         // Set the mapping name and key/value names & types
         self.0.mappings().iter().try_for_each(|(name, mapping)| {
             let mapping_object = Object::new();
             Reflect::set(&mapping_object, &"name".into(), &name.to_string().into())
-                .map_err(|_| "Failed to set property")?;
+                .map_err(|_| AleoError::serialization("Failed to set property"))?;
             Reflect::set(&mapping_object, &"key_type".into(), &mapping.key().plaintext_type().to_string().into())
-                .map_err(|_| "Failed to set property")?;
+                .map_err(|_| AleoError::serialization("Failed to set property"))?;
             Reflect::set(&mapping_object, &"value_type".into(), &mapping.value().plaintext_type().to_string().into())
-                .map_err(|_| "Failed to set property")?;
+                .map_err(|_| AleoError::serialization("Failed to set property"))?;
             mappings.push(&mapping_object);
-            Ok::<(), String>(())
+            Ok::<(), AleoError>(())
         })?;
         Ok(mappings)
     }
@@ -229,44 +348,79 @@ impl Program {
         plaintext: &PlaintextType<CurrentNetwork>,
         visibility: Option<String>,
         name: Option<String>,
-    ) -> Result<Object, String> {
+    ) -> Result<Object, AleoError> {
         let input = Object::new();
         match plaintext {
             PlaintextType::Array(array_type) => {
                 if let Some(name) = name {
-                    Reflect::set(&input, &"name".into(), &name.into()).map_err(|_| "Failed to set property")?;
+                    Reflect::set(&input, &"name".into(), &name.into())
+                        .map_err(|_| AleoError::serialization("Failed to set property"))?;
                 }
-                Reflect::set(&input, &"type".into(), &"array".into()).map_err(|_| "Failed to set property")?;
+                Reflect::set(&input, &"type".into(), &"array".into())
+                    .map_err(|_| AleoError::serialization("Failed to set property"))?;
 
                 // Set the element types of the Array and record the length
-                let element_type = self.get_plaintext_input(array_type.base_element_type(), None, None)?;
+                let element_type = self.get_plaintext_input(array_type.next_element_type(), None, None)?;
                 let length = **array_type.length();
-                Reflect::set(&input, &"element_type".into(), &element_type).map_err(|_| "Failed to set property")?;
-                Reflect::set(&input, &"length".into(), &length.into()).map_err(|_| "Failed to set property")?;
+                Reflect::set(&input, &"element_type".into(), &element_type)
+                    .map_err(|_| AleoError::serialization("Failed to set property"))?;
+                Reflect::set(&input, &"length".into(), &length.into())
+                    .map_err(|_| AleoError::serialization("Failed to set property"))?;
             }
             PlaintextType::Literal(literal_type) => {
                 if let Some(name) = name {
-                    Reflect::set(&input, &"name".into(), &name.into()).map_err(|_| "Failed to set property")?;
+                    Reflect::set(&input, &"name".into(), &name.into())
+                        .map_err(|_| AleoError::serialization("Failed to set property"))?;
                 }
                 let value_type = JsValue::from_str(&literal_type.to_string());
-                Reflect::set(&input, &"type".into(), &value_type).map_err(|_| "Failed to set property")?;
+                Reflect::set(&input, &"type".into(), &value_type)
+                    .map_err(|_| AleoError::serialization("Failed to set property"))?;
             }
             PlaintextType::Struct(struct_id) => {
                 let struct_name = struct_id.to_string();
                 if let Some(name) = name {
-                    Reflect::set(&input, &"name".into(), &name.into()).map_err(|_| "Failed to set property")?;
+                    Reflect::set(&input, &"name".into(), &name.into())
+                        .map_err(|_| AleoError::serialization("Failed to set property"))?;
                 }
-                Reflect::set(&input, &"type".into(), &"struct".into()).map_err(|_| "Failed to set property")?;
+                Reflect::set(&input, &"type".into(), &"struct".into())
+                    .map_err(|_| AleoError::serialization("Failed to set property"))?;
                 Reflect::set(&input, &"struct_id".into(), &struct_name.as_str().into())
-                    .map_err(|_| "Failed to set property")?;
+                    .map_err(|_| AleoError::serialization("Failed to set property"))?;
                 let inputs = self.get_struct_members(struct_name)?;
-                Reflect::set(&input, &"members".into(), &inputs.into()).map_err(|_| "Failed to set property")?;
+                Reflect::set(&input, &"members".into(), &inputs.into())
+                    .map_err(|_| AleoError::serialization("Failed to set property"))?;
             }
         }
         if let Some(visibility) = visibility {
-            Reflect::set(&input, &"visibility".into(), &visibility.into()).map_err(|_| "Failed to set property")?;
+            Reflect::set(&input, &"visibility".into(), &visibility.into())
+                .map_err(|_| AleoError::serialization("Failed to set property"))?;
+        }
+
+        Ok(input)
+    }
+
+    // Get the type of a function, closure or finalize input or output as a javascript object (this
+    // function is not part of the public API)
+    fn get_declared_type(&self, value_type: &impl SplitVisibility) -> Result<Object, AleoError> {
+        let (visibility, declared_type) = value_type.split_visibility();
+        match declared_type {
+            DeclaredType::Plaintext(plaintext) => {
+                self.get_plaintext_input(plaintext, visibility.map(String::from), None)
+            }
+            DeclaredType::Record(identifier) => self.get_record_members(identifier.to_string()),
+            DeclaredType::ExternalRecord(locator) => Self::get_locator_input("external_record", &locator.to_string()),
+            DeclaredType::Future(locator) => Self::get_locator_input("future", &locator.to_string()),
         }
+    }
 
+    // Get a value identified by the locator of another program as a javascript object (this function
+    // is not part of the public API)
+    fn get_locator_input(value_type: &str, locator: &str) -> Result<Object, AleoError> {
+        let input = Object::new();
+        Reflect::set(&input, &"type".into(), &value_type.into())
+            .map_err(|_| AleoError::serialization("Failed to set property"))?;
+        Reflect::set(&input, &"locator".into(), &locator.into())
+            .map_err(|_| AleoError::serialization("Failed to set property"))?;
         Ok(input)
     }
 
@@ -298,43 +452,41 @@ impl Program {
     /// const credits_record = credits_program.getRecordMembers("Credits");
     /// console.log(credits_record === expected_record); // Output should be "true"
     #[wasm_bindgen(js_name = "getRecordMembers")]
-    pub fn get_record_members(&self, record_name: String) -> Result<Object, String> {
-        let record_id = IdentifierNative::from_str(&record_name).map_err(|e| e.to_string())?;
+    pub fn get_record_members(&self, record_name: String) -> Result<Object, AleoError> {
+        let record_id = IdentifierNative::from_str(&record_name).map_err(AleoError::parse)?;
         let record = self
             .0
             .get_record(&record_id)
-            .map_err(|_| format!("struct {} not found in {}", record_name, self.0.id()))?;
+            .map_err(|_| AleoError::invalid_input(format!("record {} not found in {}", record_name, self.0.id())))?;
 
         let input = Object::new();
-        Reflect::set(&input, &"type".into(), &"record".into()).map_err(|_| "Failed to set property")?;
-        Reflect::set(&input, &"record".into(), &record_name.into()).map_err(|_| "Failed to set property")?;
+        Reflect::set(&input, &"type".into(), &"record".into())
+            .map_err(|_| AleoError::serialization("Failed to set property"))?;
+        Reflect::set(&input, &"record".into(), &record_name.into())
+            .map_err(|_| AleoError::serialization("Failed to set property"))?;
 
         let record_members = Array::new_with_length(record.entries().len() as u32);
 
         for (index, (name, member_type)) in record.entries().iter().enumerate() {
-            match member_type {
-                EntryType::Constant(plaintext) => record_members.set(
-                    index as u32,
-                    self.get_plaintext_input(plaintext, Some("constant".to_string()), Some(name.to_string()))?.into(),
-                ),
-                EntryType::Public(plaintext) => record_members.set(
-                    index as u32,
-                    self.get_plaintext_input(plaintext, Some("public".to_string()), Some(name.to_string()))?.into(),
-                ),
-                EntryType::Private(plaintext) => record_members.set(
-                    index as u32,
-                    self.get_plaintext_input(plaintext, Some("private".to_string()), Some(name.to_string()))?.into(),
-                ),
-            }
+            let (visibility, declared_type) = member_type.split_visibility();
+            let plaintext = declared_type
+                .plaintext()
+                .ok_or_else(|| AleoError::invalid_input("Record entries must be plaintexts"))?;
+            let member = self.get_plaintext_input(plaintext, visibility.map(String::from), Some(name.to_string()))?;
+            record_members.set(index as u32, member.into());
         }
 
-        Reflect::set(&input, &"members".into(), &record_members).map_err(|_| "Failed to set property")?;
+        Reflect::set(&input, &"members".into(), &record_members)
+            .map_err(|_| AleoError::serialization("Failed to set property"))?;
 
         // Adding _nonce object to record
         let _nonce = Object::new();
-        Reflect::set(&_nonce, &"name".into(), &"_nonce".into()).map_err(|_| "Failed to set property")?;
-        Reflect::set(&_nonce, &"type".into(), &"group".into()).map_err(|_| "Failed to set property")?;
-        Reflect::set(&_nonce, &"visibility".into(), &"public".into()).map_err(|_| "Failed to set property")?;
+        Reflect::set(&_nonce, &"name".into(), &"_nonce".into())
+            .map_err(|_| AleoError::serialization("Failed to set property"))?;
+        Reflect::set(&_nonce, &"type".into(), &"group".into())
+            .map_err(|_| AleoError::serialization("Failed to set property"))?;
+        Reflect::set(&_nonce, &"visibility".into(), &"public".into())
+            .map_err(|_| AleoError::serialization("Failed to set property"))?;
 
         record_members.push(&JsValue::from(_nonce));
 
@@ -388,13 +540,13 @@ impl Program {
     /// const struct_members = program.getStructMembers("token");
     /// console.log(struct_members === expected_struct_members); // Output should be "true"
     #[wasm_bindgen(js_name = "getStructMembers")]
-    pub fn get_struct_members(&self, struct_name: String) -> Result<Array, String> {
-        let struct_id = IdentifierNative::from_str(&struct_name).map_err(|e| e.to_string())?;
+    pub fn get_struct_members(&self, struct_name: String) -> Result<Array, AleoError> {
+        let struct_id = IdentifierNative::from_str(&struct_name).map_err(AleoError::parse)?;
 
         let program_struct = self
             .0
             .get_struct(&struct_id)
-            .map_err(|_| format!("struct {} not found in {}", struct_name, self.0.id()))?;
+            .map_err(|_| AleoError::invalid_input(format!("struct {} not found in {}", struct_name, self.0.id())))?;
 
         let struct_members = Array::new_with_length(program_struct.members().len() as u32);
         for (index, (name, member_type)) in program_struct.members().iter().enumerate() {
@@ -425,8 +577,8 @@ impl Program {
     ///
     /// @returns {Address} The address of the program
     #[wasm_bindgen]
-    pub fn address(&self) -> Result<Address, String> {
-        Ok(Address::from(self.0.id().to_address().map_err(|e| e.to_string())?))
+    pub fn address(&self) -> Result<Address, AleoError> {
+        Ok(Address::from(self.0.id().to_address().map_err(AleoError::invalid_input)?))
     }
 
     /// Determine equality with another program
@@ -438,6 +590,81 @@ impl Program {
         self == other
     }
 
+    /// Generate a TypeScript module for the program. The module declares interfaces for the structs
+    /// and records of the program, with functions encoding structs into their Aleo string
+    /// representation and decoding structs and record plaintexts into their interfaces. For every
+    /// function it declares `encode<Function>Inputs`, which encodes typed inputs into the strings
+    /// passed to `ProgramManager`, and `decode<Function>Outputs`, which decodes the outputs of an
+    /// execution into a typed tuple
+    ///
+    /// Types are read from `getStructMembers`, `getRecordMembers`, `getFunctionInputs` and
+    /// `getFunctionOutputs`. Literals are typed as in `RecordPlaintext.getMember`: integers of up to
+    /// 32 bits are numbers, 64 and 128 bit integers are bigints, booleans are booleans and every other
+    /// literal is a string. Records are passed and returned as strings. Records of imported programs
+    /// used by the functions are declared with the name of their program as a prefix, so their
+    /// programs must be provided as imports unless they are credits.aleo
+    ///
+    /// @param {Object | undefined} imports Object mapping the program IDs of the imports to their source code
+    /// @returns {string} TypeScript module
+    ///
+    /// @example
+    ///
+    /// const bindings = program.toTypeScript({ "token.aleo": token_program_source });
+    /// fs.writeFileSync("exchange.ts", bindings);
+    #[wasm_bindgen(js_name = "toTypeScript")]
+    pub fn to_typescript(&self, imports: Option<Object>) -> Result<String, AleoError> {
+        let mut programs = IndexMap::new();
+        for program_id in self.0.imports().keys() {
+            let import = imports
+                .as_ref()
+                .and_then(|imports| Reflect::get(imports, &program_id.to_string().into()).ok())
+                .and_then(|import| import.as_string());
+            let import = match import {
+                Some(import) => Program::from_string(&import)?,
+                None if program_id.to_string() == "credits.aleo" => Program::get_credits_program(),
+                None => continue,
+            };
+            programs.insert(*program_id, import);
+        }
+        TypeScriptGenerator::new(self, programs).generate()
+    }
+
+    /// Export the ABI of the program as versioned JSON. The ABI describes the inputs and outputs of
+    /// each function with their visibilities, the inputs of their finalize blocks, and the records,
+    /// structs and mappings of the program
+    ///
+    /// @returns {string} ABI JSON, which can be read back with `ProgramAbi.fromString`
+    ///
+    /// @example
+    ///
+    /// const abi = ProgramAbi.fromString(program.toAbi());
+    /// abi.checkInputs("transfer_public", [recipient, "5u64"]);
+    #[wasm_bindgen(js_name = "toAbi")]
+    pub fn to_abi(&self) -> String {
+        ProgramAbi::from_program(&self.0).to_string()
+    }
+
+    /// Encode javascript values into the inputs of a function, the inverse of `getFunctionInputs`.
+    /// Literals may be given as booleans, numbers, bigints or strings, with or without their type
+    /// suffix. Structs are given as objects and arrays as arrays. Records are given as record
+    /// plaintext strings, `RecordPlaintext` objects, or for records of the program as objects holding
+    /// their `owner` and entries, which are built as in `RecordPlaintext.fromObject`. Strings given
+    /// for structs and arrays are assumed to already be encoded and are passed through unchanged
+    ///
+    /// @param {string} function_name Name of the function
+    /// @param {Array} inputs Array of the values of the inputs of the function
+    /// @returns {Array} Array of input strings which can be passed to `ProgramManager`
+    ///
+    /// @example
+    ///
+    /// const inputs = program.encodeInputs("mint", [owner, 100n, { id: 1, flags: [true, false] }]);
+    /// console.log(inputs); // ["aleo1...", "100u64", "{ id: 1field, flags: [true, false] }"]
+    #[wasm_bindgen(js_name = "encodeInputs")]
+    pub fn encode_inputs(&self, function_name: &str, inputs: Array) -> Result<Array, AleoError> {
+        let inputs = Self::encode_input_values(self, function_name, &inputs.to_vec())?;
+        Ok(inputs.iter().map(|input| JsValue::from_str(input)).collect())
+    }
+
     /// Get program_imports
     ///
     /// @returns {Array} The program imports
@@ -470,6 +697,185 @@ impl Program {
     }
 }
 
+impl Program {
+    /// Encode javascript values into the input strings of a function of a program, as described in
+    /// `encodeInputs`
+    pub(crate) fn encode_input_values(
+        program: &ProgramNative,
+        function_name: &str,
+        inputs: &[JsValue],
+    ) -> Result<Vec<String>, AleoError> {
+        let function_id = IdentifierNative::from_str(function_name)
+            .map_err(|_| AleoError::parse(format!("{function_name} is an invalid identifier")))?;
+        let function = program.functions().get(&function_id).ok_or_else(|| {
+            AleoError::invalid_input(format!("function {function_name} not found in {}", program.id()))
+        })?;
+        if inputs.len() != function.inputs().len() {
+            return Err(AleoError::invalid_input(format!(
+                "Function {function_name} expects {} inputs, but {} were provided",
+                function.inputs().len(),
+                inputs.len()
+            )));
+        }
+
+        function
+            .inputs()
+            .iter()
+            .zip(inputs)
+            .map(|(input, value)| {
+                let register = input.register().to_string();
+                match input.value_type().split_visibility().1 {
+                    DeclaredType::Plaintext(plaintext_type) => match value.as_string() {
+                        Some(string) if !matches!(plaintext_type, PlaintextType::Literal(_)) => Ok(string),
+                        _ => Ok(Self::plaintext_from_js(program, plaintext_type, value, &register)?.to_string()),
+                    },
+                    DeclaredType::Record(record_id) if is_plain_object(value) => {
+                        let members = Object::assign(&Object::new(), value.unchecked_ref::<Object>());
+                        let owner = Reflect::get(&members, &"owner".into())
+                            .ok()
+                            .and_then(|owner| owner.as_string())
+                            .ok_or_else(|| AleoError::invalid_input(format!("{register}.owner must be an address")))?;
+                        let owner = Address::from_string(&owner)?;
+                        Reflect::delete_property(&members, &"owner".into()).map_err(|_| {
+                            AleoError::invalid_input(format!(
+                                "{register}.owner could not be separated from the record entries"
+                            ))
+                        })?;
+                        let record = RecordPlaintext::from_members(program, &record_id.to_string(), &owner, &members)?;
+                        Ok(record.to_string())
+                    }
+                    DeclaredType::Record(_) | DeclaredType::ExternalRecord(_) => {
+                        let record = match value.as_string() {
+                            Some(string) => string,
+                            None if value.is_object() => String::from(value.unchecked_ref::<Object>().to_string()),
+                            None => return Err(AleoError::invalid_input(format!("{register} must be a record"))),
+                        };
+                        RecordPlaintextNative::from_str(&record)
+                            .map(|record| record.to_string())
+                            .map_err(|_| AleoError::invalid_input(format!("{register} must be a record plaintext")))
+                    }
+                    DeclaredType::Future(_) => {
+                        Err(AleoError::invalid_input(format!("{register} is a future and cannot be given as an input")))
+                    }
+                }
+            })
+            .collect()
+    }
+
+    /// Convert a javascript value into a plaintext of a type defined by the program. Literals may be
+    /// given as booleans, numbers, bigints or strings, with or without their type suffix. Structs are
+    /// given as objects holding exactly the members of the struct and arrays as arrays of the
+    /// declared length. The name is used to identify the value in error messages
+    pub(crate) fn plaintext_from_js(
+        program: &ProgramNative,
+        plaintext_type: &PlaintextType<CurrentNetwork>,
+        value: &JsValue,
+        name: &str,
+    ) -> Result<PlaintextNative, AleoError> {
+        match plaintext_type {
+            PlaintextType::Literal(literal_type) => {
+                let text = if let Some(boolean) = value.as_bool() {
+                    boolean.to_string()
+                } else if let Some(number) = value.as_f64() {
+                    if number.fract() != 0.0 || !number.is_finite() {
+                        return Err(AleoError::invalid_input(format!("{name} must be an integer, found {number}")));
+                    }
+                    // Numbers beyond the safe integer range may already have been rounded by javascript
+                    if number.abs() > MAX_SAFE_INTEGER {
+                        return Err(AleoError::invalid_input(format!(
+                            "{name} is not a safe integer, pass values above 2^53 - 1 as a bigint or a string"
+                        )));
+                    }
+                    (number as i128).to_string()
+                } else if let Some(bigint) = value.dyn_ref::<BigInt>() {
+                    String::from(
+                        bigint.to_string(10).map_err(|_| AleoError::parse(format!("{name} is not a valid bigint")))?,
+                    )
+                } else if let Some(string) = value.as_string() {
+                    string
+                } else {
+                    return Err(AleoError::invalid_input(format!("{name} must be a {literal_type}")));
+                };
+
+                // Accept values with or without their type suffix
+                let literal = LiteralNative::from_str(&text)
+                    .ok()
+                    .filter(|literal| literal.to_type() == *literal_type)
+                    .or_else(|| LiteralNative::from_str(&format!("{text}{literal_type}")).ok())
+                    .filter(|literal| literal.to_type() == *literal_type)
+                    .ok_or_else(|| {
+                        AleoError::invalid_input(format!("{name} must be a {literal_type}, found {text}"))
+                    })?;
+                Ok(PlaintextNative::Literal(literal, OnceCell::new()))
+            }
+            PlaintextType::Struct(struct_id) => {
+                let program_struct = program.get_struct(struct_id).map_err(|_| {
+                    AleoError::invalid_input(format!("struct {} not found in {}", struct_id, program.id()))
+                })?;
+                if !value.is_object() || Array::is_array(value) {
+                    return Err(AleoError::invalid_input(format!(
+                        "{name} must be an object holding the members of {struct_id}"
+                    )));
+                }
+                let keys = Object::keys(value.unchecked_ref::<Object>());
+                for key in keys.iter().filter_map(|key| key.as_string()) {
+                    if !program_struct.members().keys().any(|member| member.to_string() == key) {
+                        return Err(AleoError::invalid_input(format!(
+                            "{name} has a member {key} that is not part of {struct_id}"
+                        )));
+                    }
+                }
+
+                let mut members = IndexMap::with_capacity(program_struct.members().len());
+                for (member_name, member_type) in program_struct.members() {
+                    let member_path = format!("{name}.{member_name}");
+                    let member = Reflect::get(value, &member_name.to_string().into())
+                        .ok()
+                        .filter(|member| !member.is_undefined())
+                        .ok_or_else(|| AleoError::invalid_input(format!("{member_path} is missing")))?;
+                    members.insert(*member_name, Self::plaintext_from_js(program, member_type, &member, &member_path)?);
+                }
+                Ok(PlaintextNative::Struct(members, OnceCell::new()))
+            }
+            PlaintextType::Array(array_type) => {
+                let length = **array_type.length();
+                let array = value
+                    .dyn_ref::<Array>()
+                    .ok_or_else(|| AleoError::invalid_input(format!("{name} must be an array")))?;
+                if array.length() != length {
+                    return Err(AleoError::invalid_input(format!(
+                        "{name} must have {length} elements, found {}",
+                        array.length()
+                    )));
+                }
+                let elements = array
+                    .iter()
+                    .enumerate()
+                    .map(|(index, element)| {
+                        Self::plaintext_from_js(
+                            program,
+                            array_type.next_element_type(),
+                            &element,
+                            &format!("{name}[{index}]"),
+                        )
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(PlaintextNative::Array(elements, OnceCell::new()))
+            }
+        }
+    }
+}
+
+// Determine whether a javascript value is a plain object, such as an object literal, rather than an
+// array or an instance of a class such as `RecordPlaintext`
+fn is_plain_object(value: &JsValue) -> bool {
+    if !value.is_object() || Array::is_array(value) {
+        return false;
+    }
+    let prototype = Object::get_prototype_of(value);
+    prototype.is_null() || prototype == Object::get_prototype_of(&Object::new())
+}
+
 impl Deref for Program {
     type Target = ProgramNative;
 
@@ -491,7 +897,7 @@ impl From<Program> for ProgramNative {
 }
 
 impl FromStr for Program {
-    type Err = String;
+    type Err = AleoError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Self::from_string(s)
@@ -501,6 +907,7 @@ impl FromStr for Program {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::types::native::AddressNative;
 
     use wasm_bindgen_test::*;
 
@@ -552,6 +959,42 @@ function bump_token_version:
     cast r0 r1.microcredits r1.amount r2 into r3 as Token.record;
     output r3 as Token.record;"#;
 
+    const ASYNC_PROGRAM: &str = r#"program async_test.aleo;
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+
+mapping balances:
+    key as address.public;
+    value as u64.public;
+
+closure double_amount:
+    input r0 as u64;
+    add r0 r0 into r1;
+    output r1 as u64;
+
+function mint_private:
+    input r0 as address.private;
+    input r1 as u64.private;
+    call double_amount r1 into r2;
+    cast r0 r2 into r3 as token.record;
+    output r3 as token.record;
+    output r2 as u64.public;
+
+function mint_public:
+    input r0 as address.public;
+    input r1 as u64.public;
+    async mint_public r0 r1 into r2;
+    output r2 as async_test.aleo/mint_public.future;
+
+finalize mint_public:
+    input r0 as address.public;
+    input r1 as u64.public;
+    get.or_use balances[r0] 0u64 into r2;
+    add r2 r1 into r3;
+    set r3 into balances[r0];"#;
+
     pub const NESTED_IMPORT_PROGRAM: &str = r#"// The 'imported_add_mul.aleo' program uses a nested series of imports. It imports the 'double_test.aleo' program
 // which then imports the 'multiply_test.aleo' program and implicitly uses that to perform the doubling.
 import double_test.aleo;
@@ -734,6 +1177,171 @@ function add_and_double:
         assert_eq!(format!("{:?}", inputs), format!("{:?}", expected));
     }
 
+    #[wasm_bindgen_test]
+    fn test_get_outputs_closures_and_finalize() {
+        let program = Program::from_string(ASYNC_PROGRAM).unwrap();
+
+        let outputs = program.get_function_outputs("mint_private".to_string()).unwrap();
+        let expected = array![
+            object! {
+                "type": "record",
+                "record": "token",
+                "members": array![
+                    object! {
+                        "name": "amount",
+                        "type": "u64",
+                        "visibility": "private",
+                    },
+                    object! {
+                        "name": "_nonce",
+                        "type": "group",
+                        "visibility": "public",
+                    },
+                ],
+                "register": "r3",
+            },
+            object! {
+                "type": "u64",
+                "visibility": "public",
+                "register": "r2",
+            },
+        ];
+        assert_eq!(format!("{:?}", outputs), format!("{:?}", expected));
+
+        let outputs = program.get_function_outputs("mint_public".to_string()).unwrap();
+        let expected = array![object! {
+            "type": "future",
+            "locator": "async_test.aleo/mint_public",
+            "register": "r2",
+        }];
+        assert_eq!(format!("{:?}", outputs), format!("{:?}", expected));
+
+        let closures = program.get_closures().unwrap();
+        let expected = array![object! {
+            "name": "double_amount",
+            "inputs": array![object! {
+                "type": "u64",
+                "register": "r0",
+            }],
+            "outputs": array![object! {
+                "type": "u64",
+                "register": "r1",
+            }],
+        }];
+        assert_eq!(format!("{:?}", closures), format!("{:?}", expected));
+
+        let finalize_inputs = program.get_finalize_inputs("mint_public".to_string()).unwrap();
+        let expected = array![
+            object! {
+                "type": "address",
+                "visibility": "public",
+                "register": "r0",
+            },
+            object! {
+                "type": "u64",
+                "visibility": "public",
+                "register": "r1",
+            },
+        ];
+        assert_eq!(format!("{:?}", finalize_inputs), format!("{:?}", expected));
+        assert_eq!(program.get_finalize_inputs("mint_private".to_string()).unwrap().length(), 0);
+
+        assert!(program.is_async("mint_public").unwrap());
+        assert!(!program.is_async("mint_private").unwrap());
+        assert!(program.is_async("missing").is_err());
+        assert!(program.get_function_outputs("missing".to_string()).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_to_typescript() {
+        let credits = Program::get_credits_program();
+        let bindings = credits.to_typescript(None).unwrap();
+        assert!(bindings.contains("export const PROGRAM_ID = \"credits.aleo\";"));
+        assert!(bindings.contains("export interface CreditsRecord {"));
+        assert!(bindings.contains("export function encodeTransferPublicInputs(r0: string, r1: bigint): string[] {"));
+    }
+
+    #[wasm_bindgen_test]
+    fn test_to_abi() {
+        let credits = Program::get_credits_program();
+        let abi = ProgramAbi::from_string(&credits.to_abi()).unwrap();
+        assert_eq!(abi.program(), "credits.aleo");
+        let inputs: Array = ["aleo1j7qxyunfldj2lp8hsvy7mw5k8zaqgjfyr72x2gh3x4ewgae8v5gscf5jh3", "5u64"]
+            .iter()
+            .map(|input| JsValue::from_str(input))
+            .collect();
+        assert!(abi.check_inputs("transfer_public", inputs).is_ok());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_plaintext_from_js_rejects_unsafe_numbers() {
+        let program = Program::from_string(TOKEN_ISSUE).unwrap();
+        let u64_type = PlaintextType::from_str("u64").unwrap();
+        let convert = |value: JsValue| Program::plaintext_from_js(&program, &u64_type, &value, "amount");
+
+        assert_eq!(convert(JsValue::from(MAX_SAFE_INTEGER)).unwrap().to_string(), "9007199254740991u64");
+        // 2^53 + 1 is rounded to 2^53 by javascript, so it must be passed as a bigint or a string
+        assert!(convert(JsValue::from(9_007_199_254_740_993u64 as f64)).is_err());
+        assert_eq!(convert(BigInt::from(9_007_199_254_740_993u64).into()).unwrap().to_string(), "9007199254740993u64");
+        assert_eq!(convert(JsValue::from_str("9007199254740993")).unwrap().to_string(), "9007199254740993u64");
+    }
+
+    #[wasm_bindgen_test]
+    fn test_encode_inputs() {
+        let program = Program::from_string(TOKEN_ISSUE).unwrap();
+        let owner = "aleo1j7qxyunfldj2lp8hsvy7mw5k8zaqgjfyr72x2gh3x4ewgae8v5gscf5jh3";
+        let metadata = object! { "token_id": 1, "version": "2u32", };
+        let inputs = program.encode_inputs("issue", array![owner, BigInt::from(100u64), metadata]).unwrap();
+        let inputs: Vec<String> = inputs.iter().filter_map(|input| input.as_string()).collect();
+        let metadata = PlaintextNative::from_str("{ token_id: 1u32, version: 2u32 }").unwrap().to_string();
+        assert_eq!(inputs, [owner.to_string(), "100u64".to_string(), metadata]);
+
+        // Assert encoded strings and records are accepted
+        let record = format!(
+            "{{ owner: {owner}.private, microcredits: 0u64.private, amount: 100u64.private, token_data: {{ token_id: 1u32.private, version: 2u32.private }}, _nonce: 3077450429259593211617823051143573281856129402760267155982965992208217472983group.public }}"
+        );
+        let inputs = program.encode_inputs("bump_token_version", array![
+            owner,
+            record.as_str(),
+            "{ token_id: 1u32, version: 3u32 }"
+        ]);
+        assert_eq!(inputs.unwrap().length(), 3);
+        let record_plaintext = RecordPlaintext::from_str(&record).unwrap();
+        let inputs = program.encode_inputs("bump_token_version", array![
+            owner,
+            record_plaintext,
+            "{ token_id: 1u32, version: 3u32 }"
+        ]);
+        assert_eq!(
+            inputs.unwrap().get(1).as_string().unwrap(),
+            RecordPlaintextNative::from_str(&record).unwrap().to_string()
+        );
+
+        // Assert records of the program can be built from their owner and entries
+        let token_data = object! { "token_id": 1, "version": 2, };
+        let token =
+            object! { "owner": owner, "microcredits": 0, "amount": BigInt::from(100u64), "token_data": token_data, };
+        let inputs =
+            program.encode_inputs("bump_token_version", array![owner, token, "{ token_id: 1u32, version: 3u32 }"]);
+        let token = RecordPlaintextNative::from_str(&inputs.unwrap().get(1).as_string().unwrap()).unwrap();
+        assert_eq!(**token.owner(), AddressNative::from_str(owner).unwrap());
+        assert!(token.to_string().contains("amount: 100u64.private"));
+        let token_data = object! { "token_id": 1, "version": 2, };
+        let unowned = object! { "microcredits": 0, "amount": 100, "token_data": token_data, };
+        assert!(
+            program
+                .encode_inputs("bump_token_version", array![owner, unowned, "{ token_id: 1u32, version: 3u32 }"])
+                .is_err()
+        );
+
+        // Assert values which do not match the signature are rejected
+        assert!(program.encode_inputs("issue", array![owner, 1.5, object! { "token_id": 1, "version": 2, }]).is_err());
+        assert!(program.encode_inputs("issue", array![owner, 100, object! { "token_id": 1, }]).is_err());
+        assert!(program.encode_inputs("bump_token_version", array![owner, "5u64", "{}"]).is_err());
+        assert!(program.encode_inputs("issue", array![owner]).is_err());
+        assert!(program.encode_inputs("missing", array![]).is_err());
+    }
+
     #[wasm_bindgen_test]
     fn test_get_record() {
         let credits = Program::from(ProgramNative::credits().unwrap());
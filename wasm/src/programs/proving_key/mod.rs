@@ -16,7 +16,10 @@
 
 mod credits;
 
-use crate::types::native::{FromBytes, ProvingKeyNative, ToBytes};
+use crate::{
+    types::native::{FromBytes, ProvingKeyNative, ToBytes},
+    AleoError,
+};
 
 use sha2::Digest;
 use wasm_bindgen::prelude::wasm_bindgen;
@@ -50,24 +53,24 @@ impl ProvingKey {
     /// @param {Uint8Array} bytes Byte array representation of a proving key
     /// @returns {ProvingKey}
     #[wasm_bindgen(js_name = "fromBytes")]
-    pub fn from_bytes(bytes: &[u8]) -> Result<ProvingKey, String> {
-        Ok(Self(ProvingKeyNative::from_bytes_le(bytes).map_err(|e| e.to_string())?))
+    pub fn from_bytes(bytes: &[u8]) -> Result<ProvingKey, AleoError> {
+        Ok(Self(ProvingKeyNative::from_bytes_le(bytes).map_err(AleoError::parse)?))
     }
 
     /// Create a proving key from string
     ///
     /// @param {string} String representation of the proving key
     #[wasm_bindgen(js_name = "fromString")]
-    pub fn from_string(string: &str) -> Result<ProvingKey, String> {
-        Ok(Self(ProvingKeyNative::from_str(string).map_err(|e| e.to_string())?))
+    pub fn from_string(string: &str) -> Result<ProvingKey, AleoError> {
+        Ok(Self(ProvingKeyNative::from_str(string).map_err(AleoError::parse)?))
     }
 
     /// Return the byte representation of a proving key
     ///
     /// @returns {Uint8Array} Byte array representation of a proving key
     #[wasm_bindgen(js_name = "toBytes")]
-    pub fn to_bytes(&self) -> Result<Vec<u8>, String> {
-        self.0.to_bytes_le().map_err(|_| "Failed to serialize proving key".to_string())
+    pub fn to_bytes(&self) -> Result<Vec<u8>, AleoError> {
+        self.0.to_bytes_le().map_err(|_| AleoError::serialization("Failed to serialize proving key"))
     }
 
     /// Get a string representation of the proving key
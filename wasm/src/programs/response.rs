@@ -25,7 +25,7 @@ use crate::types::native::{
     VerifyingKeyNative,
 };
 
-use crate::{Execution, KeyPair, Program, ProvingKey, VerifyingKey};
+use crate::{AleoError, Execution, KeyPair, Program, ProvingKey, VerifyingKey};
 use std::{ops::Deref, str::FromStr};
 use wasm_bindgen::{prelude::wasm_bindgen, JsValue};
 
@@ -51,16 +51,16 @@ impl ExecutionResponse {
         response: ResponseNative,
         process: &ProcessNative,
         program: &str,
-    ) -> Result<Self, String> {
-        let program = ProgramNative::from_str(program).map_err(|e| e.to_string())?;
-        let verifying_key = process
-            .get_verifying_key(program.id(), function_id)
-            .map_err(|_| format!("Could not find verifying key for {:?}/{:?}", program.id(), function_id))?;
+    ) -> Result<Self, AleoError> {
+        let program = ProgramNative::from_str(program).map_err(AleoError::parse)?;
+        let verifying_key = process.get_verifying_key(program.id(), function_id).map_err(|_| {
+            AleoError::missing_key(format!("Could not find verifying key for {:?}/{:?}", program.id(), function_id))
+        })?;
 
         Ok(Self {
             execution,
             response,
-            function_id: IdentifierNative::from_str(function_id).map_err(|e| e.to_string())?,
+            function_id: IdentifierNative::from_str(function_id).map_err(AleoError::parse)?,
             program,
             proving_key: None,
             verifying_key,
@@ -72,10 +72,10 @@ impl ExecutionResponse {
         process: &ProcessNative,
         function_id: &str,
         program_id: &ProgramIDNative,
-    ) -> Result<(), String> {
-        let proving_key = process
-            .get_proving_key(program_id, function_id)
-            .map_err(|_| format!("Could not find proving key for {:?}/{:?}", program_id, function_id))?;
+    ) -> Result<(), AleoError> {
+        let proving_key = process.get_proving_key(program_id, function_id).map_err(|_| {
+            AleoError::missing_key(format!("Could not find proving key for {:?}/{:?}", program_id, function_id))
+        })?;
         self.proving_key = Some(proving_key);
         Ok(())
     }
@@ -102,11 +102,11 @@ impl ExecutionResponse {
 
     /// Returns the program keys if present
     #[wasm_bindgen(js_name = "getKeys")]
-    pub fn get_keys(&mut self) -> Result<KeyPair, String> {
+    pub fn get_keys(&mut self) -> Result<KeyPair, AleoError> {
         if let Some(proving_key) = self.proving_key.take() {
             Ok(KeyPair::new(ProvingKey::from(proving_key), VerifyingKey::from(self.verifying_key.clone())))
         } else {
-            Err("No proving key found".to_string())
+            Err(AleoError::missing_key("No proving key found"))
         }
     }
 
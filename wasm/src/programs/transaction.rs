@@ -14,8 +14,9 @@
 // You should have received a copy of the GNU General Public License
 // along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::types::native::TransactionNative;
+use crate::{programs::transition::decrypt_transitions, types::native::TransactionNative, AleoError, ViewKey};
 
+use js_sys::Array;
 use std::str::FromStr;
 use wasm_bindgen::prelude::wasm_bindgen;
 
@@ -34,8 +35,8 @@ impl Transaction {
     /// @param {string} transaction String representation of a transaction
     /// @returns {Transaction}
     #[wasm_bindgen(js_name = fromString)]
-    pub fn from_string(transaction: &str) -> Result<Transaction, String> {
-        Transaction::from_str(transaction)
+    pub fn from_string(transaction: &str) -> Result<Transaction, AleoError> {
+        Transaction::from_str(transaction).map_err(AleoError::parse)
     }
 
     /// Get the transaction as a string. If you want to submit this transaction to the Aleo Network
@@ -71,6 +72,17 @@ impl Transaction {
             TransactionNative::Fee(..) => "fee".to_string(),
         }
     }
+
+    /// Decrypt the private inputs and outputs of the transitions in the transaction, including the
+    /// fee transition, signed by the account of a view key. Transitions signed by other accounts are skipped
+    ///
+    /// @param {ViewKey} view_key View key of the account that signed the transitions
+    /// @returns {Array} Array of { id, program, function, inputs, outputs } objects, where inputs
+    /// and outputs hold the { index, value } of each decrypted private value
+    #[wasm_bindgen(js_name = decryptTransitions)]
+    pub fn decrypt_transitions(&self, view_key: &ViewKey) -> Result<Array, AleoError> {
+        decrypt_transitions(self.0.transitions(), view_key)
+    }
 }
 
 impl From<Transaction> for TransactionNative {
@@ -86,10 +98,10 @@ impl From<TransactionNative> for Transaction {
 }
 
 impl FromStr for Transaction {
-    type Err = String;
+    type Err = anyhow::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Self(TransactionNative::from_str(s).map_err(|e| e.to_string())?))
+        Ok(Self(TransactionNative::from_str(s)?))
     }
 }
 
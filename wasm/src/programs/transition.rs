@@ -0,0 +1,198 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    types::native::{
+        CiphertextNative,
+        CurrentNetwork,
+        FieldNative,
+        Input,
+        Network,
+        Output,
+        PlaintextNative,
+        ToBits,
+        TransitionNative,
+        ViewKeyNative,
+        U16,
+    },
+    AleoError,
+};
+use js_sys::{Array, Object, Reflect};
+
+/// Private inputs and outputs of a transition decrypted with its transition view key
+pub(crate) struct DecryptedTransition {
+    id: String,
+    program: String,
+    function: String,
+    inputs: Vec<(usize, PlaintextNative)>,
+    outputs: Vec<(usize, PlaintextNative)>,
+}
+
+impl DecryptedTransition {
+    /// Decrypt the private inputs and outputs of a transition. The transition view key is derived
+    /// as `(tpk * view_key).x`, so only the view key of the signer of the transition can recover it.
+    /// Returns `None` if the view key did not sign the transition
+    pub(crate) fn new(transition: &TransitionNative, view_key: &ViewKeyNative) -> Result<Option<Self>, AleoError> {
+        // Derive the transition view key and check it against the transition commitment
+        let tvk = (*transition.tpk() * **view_key).to_x_coordinate();
+        if CurrentNetwork::hash_psd2(&[tvk]).map_err(AleoError::invalid_input)? != *transition.tcm() {
+            return Ok(None);
+        }
+
+        // Compute the function ID as `Hash(network_id, program_id, function_name)`
+        let program_id = transition.program_id();
+        let function_id = CurrentNetwork::hash_bhp1024(
+            &(
+                U16::<CurrentNetwork>::new(CurrentNetwork::ID),
+                program_id.name(),
+                program_id.network(),
+                transition.function_name(),
+            )
+                .to_bits_le(),
+        )
+        .map_err(AleoError::invalid_input)?;
+
+        // Each private value is encrypted under `Hash(function_id, tvk, index)`, where outputs are
+        // indexed after the inputs
+        let decrypt = |index: usize, ciphertext: &CiphertextNative| -> Result<PlaintextNative, AleoError> {
+            let index = FieldNative::from_u16(u16::try_from(index).map_err(AleoError::invalid_input)?);
+            let value_view_key =
+                CurrentNetwork::hash_psd4(&[function_id, tvk, index]).map_err(AleoError::invalid_input)?;
+            ciphertext.decrypt_symmetric(value_view_key).map_err(AleoError::invalid_input)
+        };
+
+        let mut inputs = Vec::new();
+        for (index, input) in transition.inputs().iter().enumerate() {
+            if let Input::Private(_, Some(ciphertext)) = input {
+                inputs.push((index, decrypt(index, ciphertext)?));
+            }
+        }
+        let num_inputs = transition.inputs().len();
+        let mut outputs = Vec::new();
+        for (index, output) in transition.outputs().iter().enumerate() {
+            if let Output::Private(_, Some(ciphertext)) = output {
+                outputs.push((index, decrypt(num_inputs + index, ciphertext)?));
+            }
+        }
+
+        Ok(Some(Self {
+            id: transition.id().to_string(),
+            program: program_id.to_string(),
+            function: transition.function_name().to_string(),
+            inputs,
+            outputs,
+        }))
+    }
+
+    // Create a javascript object holding the decrypted values
+    fn to_object(&self) -> Result<Object, AleoError> {
+        let values_to_array = |values: &[(usize, PlaintextNative)]| -> Result<Array, AleoError> {
+            let array = Array::new_with_length(values.len() as u32);
+            for (i, (index, value)) in values.iter().enumerate() {
+                let object = Object::new();
+                Reflect::set(&object, &"index".into(), &(*index as u32).into())
+                    .map_err(|_| AleoError::serialization("Failed to set property"))?;
+                Reflect::set(&object, &"value".into(), &value.to_string().into())
+                    .map_err(|_| AleoError::serialization("Failed to set property"))?;
+                array.set(i as u32, object.into());
+            }
+            Ok(array)
+        };
+        let object = Object::new();
+        Reflect::set(&object, &"id".into(), &self.id.clone().into())
+            .map_err(|_| AleoError::serialization("Failed to set property"))?;
+        Reflect::set(&object, &"program".into(), &self.program.clone().into())
+            .map_err(|_| AleoError::serialization("Failed to set property"))?;
+        Reflect::set(&object, &"function".into(), &self.function.clone().into())
+            .map_err(|_| AleoError::serialization("Failed to set property"))?;
+        Reflect::set(&object, &"inputs".into(), &values_to_array(&self.inputs)?)
+            .map_err(|_| AleoError::serialization("Failed to set property"))?;
+        Reflect::set(&object, &"outputs".into(), &values_to_array(&self.outputs)?)
+            .map_err(|_| AleoError::serialization("Failed to set property"))?;
+        Ok(object)
+    }
+}
+
+/// Decrypt the private inputs and outputs of every transition signed by a view key into an array
+/// of `{ id, program, function, inputs, outputs }` objects. Transitions signed by other accounts are skipped
+pub(crate) fn decrypt_transitions<'a>(
+    transitions: impl Iterator<Item = &'a TransitionNative>,
+    view_key: &ViewKeyNative,
+) -> Result<Array, AleoError> {
+    let array = Array::new();
+    for transition in transitions {
+        if let Some(decrypted) = DecryptedTransition::new(transition, view_key)? {
+            array.push(&decrypted.to_object()?);
+        }
+    }
+    Ok(array)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::native::{CurrentAleo, PrivateKeyNative, ProcessNative, ProgramNative};
+
+    use snarkvm_console::prelude::TestRng;
+    use std::str::FromStr;
+    use wasm_bindgen_test::*;
+
+    const PRIVATE_PROGRAM: &str = r#"program private_sum.aleo;
+
+function sum:
+    input r0 as u32.private;
+    input r1 as u32.public;
+    input r2 as u32.private;
+    add r0 r1 into r3;
+    add r3 r2 into r4;
+    output r3 as u32.private;
+    output r4 as u32.public;
+    output r2 as u32.private;
+"#;
+
+    #[wasm_bindgen_test]
+    fn test_decrypt_transition_private_values() {
+        let mut rng = TestRng::default();
+        let private_key = PrivateKeyNative::new(&mut rng).unwrap();
+        let view_key = ViewKeyNative::try_from(private_key).unwrap();
+        let other_view_key = ViewKeyNative::try_from(PrivateKeyNative::new(&mut rng).unwrap()).unwrap();
+
+        let mut process = ProcessNative::load_web().unwrap();
+        process.add_program(&ProgramNative::from_str(PRIVATE_PROGRAM).unwrap()).unwrap();
+        let authorization = process
+            .authorize::<CurrentAleo, _>(
+                &private_key,
+                "private_sum.aleo",
+                "sum",
+                ["1u32", "2u32", "4u32"].into_iter(),
+                &mut rng,
+            )
+            .unwrap();
+        let transition = authorization.transitions().values().next().unwrap().clone();
+
+        let decrypted = DecryptedTransition::new(&transition, &view_key).unwrap().unwrap();
+        assert_eq!(decrypted.program, "private_sum.aleo");
+        assert_eq!(decrypted.function, "sum");
+        let inputs = decrypted.inputs.iter().map(|(i, value)| (*i, value.to_string())).collect::<Vec<_>>();
+        assert_eq!(inputs, vec![(0, "1u32".to_string()), (2, "4u32".to_string())]);
+        let outputs = decrypted.outputs.iter().map(|(i, value)| (*i, value.to_string())).collect::<Vec<_>>();
+        assert_eq!(outputs, vec![(0, "3u32".to_string()), (2, "4u32".to_string())]);
+
+        // Assert that transitions signed by other accounts are skipped
+        assert!(DecryptedTransition::new(&transition, &other_view_key).unwrap().is_none());
+        assert_eq!(decrypt_transitions([&transition].into_iter(), &other_view_key).unwrap().length(), 0);
+    }
+}
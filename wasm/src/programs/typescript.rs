@@ -0,0 +1,506 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    types::native::{LocatorNative, ProgramIDNative},
+    AleoError,
+    Program,
+};
+
+use indexmap::{IndexMap, IndexSet};
+use js_sys::{Array, Reflect};
+use std::{fmt::Write, str::FromStr};
+use wasm_bindgen::{JsCast, JsValue};
+
+/// Helpers shared by every generated module. Aleo values are parsed into nested strings, arrays and
+/// objects, then converted into their typed representation by the generated decoders
+const RUNTIME: &str = r#"type AleoValue = string | AleoValue[] | { [member: string]: AleoValue };
+
+function parseAleoValue(text: string): AleoValue {
+  const tokens = text.match(/[{}\[\],:]|[^\s{}\[\],:]+/g) ?? [];
+  let position = 0;
+  const parse = (): AleoValue => {
+    const token = tokens[position++];
+    if (token === undefined) throw new Error("Unexpected end of Aleo value");
+    if (token === "{") {
+      const value: { [member: string]: AleoValue } = {};
+      while (tokens[position] !== "}") {
+        const name = tokens[position];
+        position += 2;
+        value[name] = parse();
+        if (tokens[position] === ",") position++;
+      }
+      position++;
+      return value;
+    }
+    if (token === "[") {
+      const value: AleoValue[] = [];
+      while (tokens[position] !== "]") {
+        value.push(parse());
+        if (tokens[position] === ",") position++;
+      }
+      position++;
+      return value;
+    }
+    return token;
+  };
+  return parse();
+}
+
+function asStruct(value: AleoValue): { [member: string]: AleoValue } {
+  if (typeof value === "string" || Array.isArray(value)) throw new Error("Expected an Aleo struct");
+  return value;
+}
+
+function asArray(value: AleoValue): AleoValue[] {
+  if (!Array.isArray(value)) throw new Error("Expected an Aleo array");
+  return value;
+}
+
+function encodeLiteral(value: boolean | number | bigint | string, type: string): string {
+  const literal = String(value);
+  if (["address", "boolean", "signature", "string"].includes(type) || literal.endsWith(type)) return literal;
+  return `${literal}${type}`;
+}
+
+function decodeLiteral(value: AleoValue, type: string): boolean | number | bigint | string {
+  if (typeof value !== "string") throw new Error(`Expected a ${type} literal`);
+  const literal = value.replace(/\.(constant|public|private)$/, "");
+  if (type === "boolean") return literal === "true";
+  const integer = /^[iu](8|16|32|64|128)$/.exec(type);
+  if (integer) {
+    const digits = literal.slice(0, -type.length);
+    return Number(integer[1]) <= 32 ? Number(digits) : BigInt(digits);
+  }
+  return literal;
+}
+"#;
+
+/// Generator of a TypeScript module for a program. The module declares an interface with an encoder
+/// and a decoder for every struct, an interface with a decoder for every record, and typed functions
+/// encoding the inputs and decoding the outputs of every function of the program
+///
+/// Types are read from the descriptions returned by `getStructMembers`, `getRecordMembers`,
+/// `getFunctionInputs` and `getFunctionOutputs`. Literals are typed as in `RecordPlaintext.getMember`:
+/// booleans and integers of up to 32 bits are booleans and numbers, 64 and 128 bit integers are
+/// bigints and every other literal is a string. Records, futures and records of other programs are
+/// passed and returned as strings
+pub(crate) struct TypeScriptGenerator<'a> {
+    program: &'a Program,
+    imports: IndexMap<ProgramIDNative, Program>,
+}
+
+impl<'a> TypeScriptGenerator<'a> {
+    pub(crate) fn new(program: &'a Program, imports: IndexMap<ProgramIDNative, Program>) -> Self {
+        Self { program, imports }
+    }
+
+    /// Generate the TypeScript module of the program
+    pub(crate) fn generate(&self) -> Result<String, AleoError> {
+        let program_id = *(**self.program).id();
+        let mut module = String::new();
+        writeln!(module, "// TypeScript bindings for {program_id}, generated from the program source. Do not edit.")
+            .unwrap();
+        writeln!(module).unwrap();
+        module.push_str(RUNTIME);
+        writeln!(module).unwrap();
+        writeln!(module, "export const PROGRAM_ID = \"{program_id}\";").unwrap();
+
+        for struct_id in self.program.structs().keys() {
+            self.write_struct(&mut module, &program_id, &struct_id.to_string())?;
+        }
+        for record_id in self.program.records().keys() {
+            self.write_record(&mut module, &program_id, &record_id.to_string())?;
+        }
+
+        // Declare the records of other programs used by the functions, along with their structs
+        let mut functions = Vec::new();
+        let mut external_records = IndexSet::new();
+        for function_id in self.program.functions().keys() {
+            let inputs = self.program.get_function_inputs(function_id.to_string())?;
+            let outputs = self.program.get_function_outputs(function_id.to_string())?;
+            for value in inputs.iter().chain(outputs.iter()) {
+                if get_string(&value, "type")? == "external_record" {
+                    let locator = LocatorNative::from_str(&get_string(&value, "locator")?).map_err(AleoError::parse)?;
+                    external_records.insert((*locator.program_id(), locator.resource().to_string()));
+                }
+            }
+            functions.push((function_id.to_string(), inputs, outputs));
+        }
+        let mut external_structs = IndexSet::new();
+        for (program_id, record_id) in &external_records {
+            let record = self.get_program(program_id)?.get_record_members(record_id.clone())?;
+            for member in get_members(&record)?.iter() {
+                self.collect_structs(program_id, &member, &mut external_structs)?;
+            }
+        }
+        for (program_id, struct_id) in &external_structs {
+            self.write_struct(&mut module, program_id, struct_id)?;
+        }
+        for (program_id, record_id) in &external_records {
+            self.write_record(&mut module, program_id, record_id)?;
+        }
+
+        for (function_id, inputs, outputs) in &functions {
+            self.write_function(&mut module, function_id, inputs, outputs)?;
+        }
+        Ok(module)
+    }
+
+    // Get the program defining a type, which is either the program itself or one of its imports
+    fn get_program(&self, program_id: &ProgramIDNative) -> Result<&Program, AleoError> {
+        if program_id == (**self.program).id() {
+            return Ok(self.program);
+        }
+        self.imports.get(program_id).ok_or_else(|| {
+            AleoError::invalid_input(format!("Program import {program_id} not found in imports provided"))
+        })
+    }
+
+    // Name of the TypeScript type of a struct or record. Types of other programs are prefixed by
+    // the name of their program
+    fn type_name(&self, program_id: &ProgramIDNative, identifier: &str) -> String {
+        if program_id == (**self.program).id() {
+            pascal_case(identifier)
+        } else {
+            format!("{}{}", pascal_case(&program_id.name().to_string()), pascal_case(identifier))
+        }
+    }
+
+    // Collect the structs used by a type description, including the structs nested in them
+    fn collect_structs(
+        &self,
+        program_id: &ProgramIDNative,
+        description: &JsValue,
+        structs: &mut IndexSet<(ProgramIDNative, String)>,
+    ) -> Result<(), AleoError> {
+        match get_string(description, "type")?.as_str() {
+            "array" => self.collect_structs(program_id, &get_element_type(description)?, structs),
+            "struct" => {
+                if structs.insert((*program_id, get_string(description, "struct_id")?)) {
+                    for member in get_members(description)?.iter() {
+                        self.collect_structs(program_id, &member, structs)?;
+                    }
+                }
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    // Get the TypeScript type of a type description
+    fn ts_type(&self, program_id: &ProgramIDNative, description: &JsValue) -> Result<String, AleoError> {
+        Ok(match get_string(description, "type")?.as_str() {
+            "array" => format!("Array<{}>", self.ts_type(program_id, &get_element_type(description)?)?),
+            "struct" => self.type_name(program_id, &get_string(description, "struct_id")?),
+            "record" | "external_record" | "future" => "string".to_string(),
+            literal_type => literal_ts_type(literal_type).to_string(),
+        })
+    }
+
+    // Get the TypeScript expression encoding a typed value into its Aleo string representation
+    fn encode(&self, program_id: &ProgramIDNative, description: &JsValue, expr: &str) -> Result<String, AleoError> {
+        Ok(match get_string(description, "type")?.as_str() {
+            "array" => format!(
+                "`[${{{expr}.map((element) => {}).join(\", \")}}]`",
+                self.encode(program_id, &get_element_type(description)?, "element")?
+            ),
+            "struct" => {
+                format!("encode{}({expr})", self.type_name(program_id, &get_string(description, "struct_id")?))
+            }
+            "record" | "external_record" | "future" => expr.to_string(),
+            literal_type => format!("encodeLiteral({expr}, \"{literal_type}\")"),
+        })
+    }
+
+    // Get the TypeScript expression decoding a parsed Aleo plaintext into its typed representation
+    fn decode(&self, program_id: &ProgramIDNative, description: &JsValue, expr: &str) -> Result<String, AleoError> {
+        Ok(match get_string(description, "type")?.as_str() {
+            "array" => format!(
+                "asArray({expr}).map((element) => {})",
+                self.decode(program_id, &get_element_type(description)?, "element")?
+            ),
+            "struct" => {
+                format!("decode{}({expr})", self.type_name(program_id, &get_string(description, "struct_id")?))
+            }
+            value_type @ ("record" | "external_record" | "future") => {
+                return Err(AleoError::invalid_input(format!("{value_type} values are not plaintexts")));
+            }
+            literal_type => {
+                format!("(decodeLiteral({expr}, \"{literal_type}\") as {})", literal_ts_type(literal_type))
+            }
+        })
+    }
+
+    // Declare the interface of a struct along with its encoder and decoder
+    fn write_struct(
+        &self,
+        module: &mut String,
+        program_id: &ProgramIDNative,
+        struct_id: &str,
+    ) -> Result<(), AleoError> {
+        let members = self.get_program(program_id)?.get_struct_members(struct_id.to_string())?;
+        let name = self.type_name(program_id, struct_id);
+
+        let mut fields = Vec::new();
+        let mut encoded = Vec::new();
+        let mut decoded = Vec::new();
+        for member in members.iter() {
+            let member_name = get_string(&member, "name")?;
+            fields.push(format!("{member_name}: {}", self.ts_type(program_id, &member)?));
+            let value = self.encode(program_id, &member, &format!("value.{member_name}"))?;
+            encoded.push(format!("{member_name}: ${{{value}}}"));
+            let value = self.decode(program_id, &member, &format!("members[\"{member_name}\"]"))?;
+            decoded.push(format!("{member_name}: {value}"));
+        }
+
+        writeln!(module, "\n/** Struct `{struct_id}` of {program_id} */").unwrap();
+        writeln!(module, "export interface {name} {{").unwrap();
+        for field in &fields {
+            writeln!(module, "  {field};").unwrap();
+        }
+        writeln!(module, "}}").unwrap();
+
+        writeln!(module, "\nexport function encode{name}(value: {name}): string {{").unwrap();
+        writeln!(module, "  return `{{ {} }}`;", encoded.join(", ")).unwrap();
+        writeln!(module, "}}").unwrap();
+
+        writeln!(module, "\nexport function decode{name}(value: AleoValue): {name} {{").unwrap();
+        writeln!(module, "  const members = asStruct(value);").unwrap();
+        writeln!(module, "  return {{").unwrap();
+        for value in &decoded {
+            writeln!(module, "    {value},").unwrap();
+        }
+        writeln!(module, "  }};").unwrap();
+        writeln!(module, "}}").unwrap();
+        Ok(())
+    }
+
+    // Declare the interface of a record along with the decoder of its plaintext. The members
+    // described by `getRecordMembers` include the nonce but not the owner
+    fn write_record(
+        &self,
+        module: &mut String,
+        program_id: &ProgramIDNative,
+        record_id: &str,
+    ) -> Result<(), AleoError> {
+        let record = self.get_program(program_id)?.get_record_members(record_id.to_string())?;
+        let name = format!("{}Record", self.type_name(program_id, record_id));
+
+        let mut fields = vec!["owner: string".to_string()];
+        let mut decoded = vec!["owner: (decodeLiteral(members[\"owner\"], \"address\") as string)".to_string()];
+        for member in get_members(&record)?.iter() {
+            let member_name = get_string(&member, "name")?;
+            fields.push(format!("{member_name}: {}", self.ts_type(program_id, &member)?));
+            let value = self.decode(program_id, &member, &format!("members[\"{member_name}\"]"))?;
+            decoded.push(format!("{member_name}: {value}"));
+        }
+
+        writeln!(module, "\n/** Record `{record_id}` of {program_id} */").unwrap();
+        writeln!(module, "export interface {name} {{").unwrap();
+        for field in &fields {
+            writeln!(module, "  {field};").unwrap();
+        }
+        writeln!(module, "}}").unwrap();
+
+        writeln!(module, "\nexport function decode{name}(plaintext: string): {name} {{").unwrap();
+        writeln!(module, "  const members = asStruct(parseAleoValue(plaintext));").unwrap();
+        writeln!(module, "  return {{").unwrap();
+        for value in &decoded {
+            writeln!(module, "    {value},").unwrap();
+        }
+        writeln!(module, "  }};").unwrap();
+        writeln!(module, "}}").unwrap();
+        Ok(())
+    }
+
+    // Declare the functions encoding the inputs and decoding the outputs of a function
+    fn write_function(
+        &self,
+        module: &mut String,
+        function_id: &str,
+        function_inputs: &Array,
+        function_outputs: &Array,
+    ) -> Result<(), AleoError> {
+        let program_id = *(**self.program).id();
+        let name = pascal_case(function_id);
+
+        let mut parameters = Vec::new();
+        let mut inputs = Vec::new();
+        for input in function_inputs.iter() {
+            let register = get_string(&input, "register")?;
+            parameters.push(format!("{register}: {}", self.ts_type(&program_id, &input)?));
+            inputs.push(self.encode(&program_id, &input, &register)?);
+        }
+        writeln!(module, "\n/** Encode the inputs of `{program_id}/{function_id}` */").unwrap();
+        writeln!(module, "export function encode{name}Inputs({}): string[] {{", parameters.join(", ")).unwrap();
+        writeln!(module, "  return [{}];", inputs.join(", ")).unwrap();
+        writeln!(module, "}}").unwrap();
+
+        let mut output_types = Vec::new();
+        let mut outputs = Vec::new();
+        for (index, output) in function_outputs.iter().enumerate() {
+            output_types.push(self.ts_type(&program_id, &output)?);
+            match get_string(&output, "type")?.as_str() {
+                "record" | "external_record" | "future" => outputs.push(format!("outputs[{index}]")),
+                _ => outputs.push(self.decode(&program_id, &output, &format!("parseAleoValue(outputs[{index}])"))?),
+            }
+        }
+        writeln!(module, "\n/** Decode the outputs of `{program_id}/{function_id}` */").unwrap();
+        writeln!(module, "export function decode{name}Outputs(outputs: string[]): [{}] {{", output_types.join(", "))
+            .unwrap();
+        writeln!(module, "  return [{}];", outputs.join(", ")).unwrap();
+        writeln!(module, "}}").unwrap();
+        Ok(())
+    }
+}
+
+// Get a string property of a type description
+fn get_string(description: &JsValue, property: &str) -> Result<String, AleoError> {
+    Reflect::get(description, &property.into())
+        .ok()
+        .and_then(|value| value.as_string())
+        .ok_or_else(|| AleoError::invalid_input(format!("Type description has no {property}")))
+}
+
+// Get the element type of an array type description
+fn get_element_type(description: &JsValue) -> Result<JsValue, AleoError> {
+    Reflect::get(description, &"element_type".into())
+        .map_err(|_| AleoError::invalid_input("Array type description has no element_type"))
+}
+
+// Get the member descriptions of a struct or record type description
+fn get_members(description: &JsValue) -> Result<Array, AleoError> {
+    let members = Reflect::get(description, &"members".into())
+        .map_err(|_| AleoError::invalid_input("Type description has no members"))?;
+    members.dyn_into::<Array>().map_err(|_| AleoError::invalid_input("Type description members must be an array"))
+}
+
+// Get the TypeScript type of a literal type, matching the values returned by
+// `RecordPlaintext.getMember`
+fn literal_ts_type(literal_type: &str) -> &'static str {
+    match literal_type {
+        "boolean" => "boolean",
+        "i8" | "i16" | "i32" | "u8" | "u16" | "u32" => "number",
+        "i64" | "i128" | "u64" | "u128" => "bigint",
+        _ => "string",
+    }
+}
+
+// Convert a snake case identifier such as `token_metadata` into `TokenMetadata`
+fn pascal_case(identifier: &str) -> String {
+    identifier
+        .split('_')
+        .map(|word| {
+            let mut characters = word.chars();
+            match characters.next() {
+                Some(first) => first.to_uppercase().chain(characters).collect::<String>(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use wasm_bindgen_test::*;
+
+    const TOKEN_PROGRAM: &str = r#"import credits.aleo;
+
+program token_bindings.aleo;
+
+struct token_metadata:
+    token_id as u32;
+    flags as [boolean; 2u32];
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+    metadata as token_metadata.private;
+
+function mint:
+    input r0 as address.private;
+    input r1 as u64.private;
+    input r2 as token_metadata.private;
+    cast r0 r1 r2 into r3 as token.record;
+    output r3 as token.record;
+    output r2 as token_metadata.public;
+
+function burn:
+    input r0 as token.record;
+    input r1 as credits.aleo/credits.record;
+    output r0.amount as u64.public;
+    output r1 as credits.aleo/credits.record;"#;
+
+    #[wasm_bindgen_test]
+    fn test_generate_typescript() {
+        let program = Program::from_string(TOKEN_PROGRAM).unwrap();
+        let mut imports = IndexMap::new();
+        imports.insert(ProgramIDNative::from_str("credits.aleo").unwrap(), Program::get_credits_program());
+        let module = TypeScriptGenerator::new(&program, imports).generate().unwrap();
+
+        assert!(module.contains("export const PROGRAM_ID = \"token_bindings.aleo\";"));
+        assert!(module.contains("export interface TokenMetadata {\n  token_id: number;\n  flags: Array<boolean>;\n}"));
+        assert!(module.contains(
+            "return `{ token_id: ${encodeLiteral(value.token_id, \"u32\")}, flags: ${`[${value.flags.map((element) => encodeLiteral(element, \"boolean\")).join(\", \")}]`} }`;"
+        ));
+        assert!(module.contains("export interface TokenRecord {\n  owner: string;\n  amount: bigint;\n  metadata: TokenMetadata;\n  _nonce: string;\n}"));
+        assert!(module.contains("export function decodeTokenRecord(plaintext: string): TokenRecord {"));
+        assert!(module.contains(
+            "export function encodeMintInputs(r0: string, r1: bigint, r2: TokenMetadata): string[] {\n  return [encodeLiteral(r0, \"address\"), encodeLiteral(r1, \"u64\"), encodeTokenMetadata(r2)];"
+        ));
+        assert!(module.contains("export function decodeMintOutputs(outputs: string[]): [string, TokenMetadata] {"));
+
+        // Assert records of imported programs are declared with the name of their program
+        assert!(module.contains("export interface CreditsCreditsRecord {\n  owner: string;\n  microcredits: bigint;"));
+        assert!(
+            module.contains("export function encodeBurnInputs(r0: string, r1: string): string[] {\n  return [r0, r1];")
+        );
+        assert!(module.contains(
+            "export function decodeBurnOutputs(outputs: string[]): [bigint, string] {\n  return [(decodeLiteral(parseAleoValue(outputs[0]), \"u64\") as bigint), outputs[1]];"
+        ));
+    }
+
+    #[wasm_bindgen_test]
+    fn test_generate_typescript_requires_imports() {
+        let program = Program::from_string(TOKEN_PROGRAM).unwrap();
+        assert!(TypeScriptGenerator::new(&program, IndexMap::new()).generate().is_err());
+        assert_eq!(pascal_case("transfer_public_to_private"), "TransferPublicToPrivate");
+    }
+
+    #[wasm_bindgen_test]
+    fn test_generate_typescript_nested_arrays() {
+        let program = Program::from_string(
+            r#"program grid.aleo;
+
+struct board:
+    cells as [[u8; 2u32]; 3u32];
+
+function flip:
+    input r0 as board.private;
+    output r0 as board.private;"#,
+        )
+        .unwrap();
+        let module = TypeScriptGenerator::new(&program, IndexMap::new()).generate().unwrap();
+
+        assert!(module.contains("export interface Board {\n  cells: Array<Array<number>>;\n}"));
+        assert!(module.contains(
+            "cells: asArray(members[\"cells\"]).map((element) => asArray(element).map((element) => (decodeLiteral(element, \"u8\") as number)))"
+        ));
+    }
+}
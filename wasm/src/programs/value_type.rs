@@ -0,0 +1,94 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::types::native::{
+    CurrentNetwork,
+    EntryType,
+    FinalizeType,
+    IdentifierNative,
+    LocatorNative,
+    PlaintextType,
+    RegisterType,
+    ValueType,
+};
+
+/// Type of a value declared by a program, once its visibility is split off
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum DeclaredType<'a> {
+    Plaintext(&'a PlaintextType<CurrentNetwork>),
+    Record(&'a IdentifierNative),
+    ExternalRecord(&'a LocatorNative),
+    Future(&'a LocatorNative),
+}
+
+impl<'a> DeclaredType<'a> {
+    /// Returns the plaintext type of the value, unless it is a record or a future
+    pub(crate) fn plaintext(&self) -> Option<&'a PlaintextType<CurrentNetwork>> {
+        match self {
+            Self::Plaintext(plaintext_type) => Some(plaintext_type),
+            _ => None,
+        }
+    }
+}
+
+/// Split the type of a value declared by a program into its visibility and its type. Values of
+/// closures, records and futures have no visibility, and the inputs of finalize blocks are public
+pub(crate) trait SplitVisibility {
+    fn split_visibility(&self) -> (Option<&'static str>, DeclaredType<'_>);
+}
+
+impl SplitVisibility for ValueType<CurrentNetwork> {
+    fn split_visibility(&self) -> (Option<&'static str>, DeclaredType<'_>) {
+        match self {
+            Self::Constant(plaintext_type) => (Some("constant"), DeclaredType::Plaintext(plaintext_type)),
+            Self::Public(plaintext_type) => (Some("public"), DeclaredType::Plaintext(plaintext_type)),
+            Self::Private(plaintext_type) => (Some("private"), DeclaredType::Plaintext(plaintext_type)),
+            Self::Record(record_id) => (None, DeclaredType::Record(record_id)),
+            Self::ExternalRecord(locator) => (None, DeclaredType::ExternalRecord(locator)),
+            Self::Future(locator) => (None, DeclaredType::Future(locator)),
+        }
+    }
+}
+
+impl SplitVisibility for EntryType<CurrentNetwork> {
+    fn split_visibility(&self) -> (Option<&'static str>, DeclaredType<'_>) {
+        match self {
+            Self::Constant(plaintext_type) => (Some("constant"), DeclaredType::Plaintext(plaintext_type)),
+            Self::Public(plaintext_type) => (Some("public"), DeclaredType::Plaintext(plaintext_type)),
+            Self::Private(plaintext_type) => (Some("private"), DeclaredType::Plaintext(plaintext_type)),
+        }
+    }
+}
+
+impl SplitVisibility for FinalizeType<CurrentNetwork> {
+    fn split_visibility(&self) -> (Option<&'static str>, DeclaredType<'_>) {
+        match self {
+            Self::Plaintext(plaintext_type) => (Some("public"), DeclaredType::Plaintext(plaintext_type)),
+            Self::Future(locator) => (None, DeclaredType::Future(locator)),
+        }
+    }
+}
+
+impl SplitVisibility for RegisterType<CurrentNetwork> {
+    fn split_visibility(&self) -> (Option<&'static str>, DeclaredType<'_>) {
+        match self {
+            Self::Plaintext(plaintext_type) => (None, DeclaredType::Plaintext(plaintext_type)),
+            Self::Record(record_id) => (None, DeclaredType::Record(record_id)),
+            Self::ExternalRecord(locator) => (None, DeclaredType::ExternalRecord(locator)),
+            Self::Future(locator) => (None, DeclaredType::Future(locator)),
+        }
+    }
+}
@@ -17,7 +17,10 @@
 mod credits;
 mod metadata;
 
-use crate::types::native::{CurrentNetwork, FromBytes, Network, ToBytes, VerifyingKeyNative};
+use crate::{
+    types::native::{CurrentNetwork, FromBytes, Network, ToBytes, VerifyingKeyNative},
+    AleoError,
+};
 
 use sha2::Digest;
 use wasm_bindgen::prelude::wasm_bindgen;
@@ -53,8 +56,8 @@ impl VerifyingKey {
     /// @param {Uint8Array} bytes Byte representation of a verifying key
     /// @returns {VerifyingKey}
     #[wasm_bindgen(js_name = "fromBytes")]
-    pub fn from_bytes(bytes: &[u8]) -> Result<VerifyingKey, String> {
-        Ok(Self(VerifyingKeyNative::from_bytes_le(bytes).map_err(|e| e.to_string())?))
+    pub fn from_bytes(bytes: &[u8]) -> Result<VerifyingKey, AleoError> {
+        Ok(Self(VerifyingKeyNative::from_bytes_le(bytes).map_err(AleoError::parse)?))
     }
 
     /// Create a verifying key from string
@@ -62,16 +65,16 @@ impl VerifyingKey {
     /// @param {String} string String representation of a verifying key
     /// @returns {VerifyingKey}
     #[wasm_bindgen(js_name = "fromString")]
-    pub fn from_string(string: &str) -> Result<VerifyingKey, String> {
-        Ok(Self(VerifyingKeyNative::from_str(string).map_err(|e| e.to_string())?))
+    pub fn from_string(string: &str) -> Result<VerifyingKey, AleoError> {
+        Ok(Self(VerifyingKeyNative::from_str(string).map_err(AleoError::parse)?))
     }
 
     /// Create a byte array from a verifying key
     ///
     /// @returns {Uint8Array} Byte representation of a verifying key
     #[wasm_bindgen(js_name = "toBytes")]
-    pub fn to_bytes(&self) -> Result<Vec<u8>, String> {
-        self.0.to_bytes_le().map_err(|_| "Failed to serialize verifying key".to_string())
+    pub fn to_bytes(&self) -> Result<Vec<u8>, AleoError> {
+        self.0.to_bytes_le().map_err(|_| AleoError::serialization("Failed to serialize verifying key"))
     }
 
     /// Get a string representation of the verifying key
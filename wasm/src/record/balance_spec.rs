@@ -0,0 +1,149 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::record_plaintext::parse_member_path;
+use crate::AleoError;
+
+use crate::types::native::{IdentifierNative, LiteralType, ProgramIDNative};
+use std::str::FromStr;
+use wasm_bindgen::prelude::*;
+
+/// Description of where the balance of a record is stored: the program and name of the record, the
+/// path of the member holding the balance and its integer type. It lets records of token programs
+/// be selected and validated the same way as credits.aleo records
+#[wasm_bindgen]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BalanceSpec {
+    program_id: ProgramIDNative,
+    record_name: IdentifierNative,
+    path: String,
+    integer_type: LiteralType,
+}
+
+#[wasm_bindgen]
+impl BalanceSpec {
+    /// Create a balance spec for the records of a program
+    ///
+    /// @param {string} program_id Program ID of the program defining the record
+    /// @param {string} record_name Name of the record
+    /// @param {string} path Path of the member holding the balance, for example `amount` or `token.amount`
+    /// @param {string} integer_type Integer type of the balance, for example `u64` or `u128`
+    /// @returns {BalanceSpec} Balance spec
+    ///
+    /// @example
+    ///
+    /// const spec = new BalanceSpec("token_registry.aleo", "Token", "amount", "u128");
+    #[wasm_bindgen(constructor)]
+    pub fn new(program_id: &str, record_name: &str, path: &str, integer_type: &str) -> Result<BalanceSpec, AleoError> {
+        let program_id = ProgramIDNative::from_str(program_id)
+            .map_err(|_| AleoError::parse(format!("{program_id} is an invalid program name")))?;
+        let record_name = IdentifierNative::from_str(record_name)
+            .map_err(|_| AleoError::parse(format!("{record_name} is an invalid identifier")))?;
+        parse_member_path(path)?;
+        let integer_type = match LiteralType::from_str(integer_type) {
+            Ok(
+                literal_type @ (LiteralType::I8
+                | LiteralType::I16
+                | LiteralType::I32
+                | LiteralType::I64
+                | LiteralType::I128
+                | LiteralType::U8
+                | LiteralType::U16
+                | LiteralType::U32
+                | LiteralType::U64
+                | LiteralType::U128),
+            ) => literal_type,
+            _ => return Err(AleoError::invalid_input(format!("{integer_type} is not an integer type"))),
+        };
+        Ok(Self { program_id, record_name, path: path.to_string(), integer_type })
+    }
+
+    /// Returns the balance spec of credits.aleo records, whose balance is the `microcredits` member
+    ///
+    /// @returns {BalanceSpec} Balance spec of credits records
+    pub fn credits() -> BalanceSpec {
+        Self {
+            program_id: ProgramIDNative::from_str("credits.aleo").unwrap(),
+            record_name: IdentifierNative::from_str("credits").unwrap(),
+            path: "microcredits".to_string(),
+            integer_type: LiteralType::U64,
+        }
+    }
+
+    /// Returns the program ID of the program defining the record
+    ///
+    /// @returns {string} Program ID
+    #[wasm_bindgen(js_name = programId)]
+    pub fn program_id(&self) -> String {
+        self.program_id.to_string()
+    }
+
+    /// Returns the name of the record
+    ///
+    /// @returns {string} Record name
+    #[wasm_bindgen(js_name = recordName)]
+    pub fn record_name(&self) -> String {
+        self.record_name.to_string()
+    }
+
+    /// Returns the path of the member holding the balance
+    ///
+    /// @returns {string} Member path
+    pub fn path(&self) -> String {
+        self.path.clone()
+    }
+
+    /// Returns the integer type of the balance
+    ///
+    /// @returns {string} Integer type
+    #[wasm_bindgen(js_name = integerType)]
+    pub fn integer_type(&self) -> String {
+        self.integer_type.to_string()
+    }
+}
+
+impl BalanceSpec {
+    /// Returns the path of the member holding the balance
+    pub(crate) fn member_path(&self) -> &str {
+        &self.path
+    }
+
+    /// Returns the integer type of the balance
+    pub(crate) fn literal_type(&self) -> LiteralType {
+        self.integer_type
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use wasm_bindgen_test::*;
+
+    #[wasm_bindgen_test]
+    fn test_balance_spec_validation() {
+        let spec = BalanceSpec::new("token_test.aleo", "token", "balance.amount", "u128").unwrap();
+        assert_eq!(spec.program_id(), "token_test.aleo");
+        assert_eq!(spec.record_name(), "token");
+        assert_eq!(spec.path(), "balance.amount");
+        assert_eq!(spec.integer_type(), "u128");
+        assert_eq!(BalanceSpec::new("credits.aleo", "credits", "microcredits", "u64").unwrap(), BalanceSpec::credits());
+
+        assert!(BalanceSpec::new("token_test", "token", "amount", "u64").is_err());
+        assert!(BalanceSpec::new("token_test.aleo", "token", "amount[", "u64").is_err());
+        assert!(BalanceSpec::new("token_test.aleo", "token", "amount", "field").is_err());
+    }
+}
@@ -0,0 +1,356 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{BalanceSpec, RecordPlaintext};
+use crate::{account::PrivateKey, AleoError, Balance};
+
+use js_sys::{Array, Object, Reflect};
+use std::collections::HashSet;
+use wasm_bindgen::prelude::*;
+
+/// Number of fee candidates kept when searching for records to join. Two of them may be consumed
+/// by the join itself, so the third always leaves a distinct record to pay the fee
+const FEE_CANDIDATES: usize = 3;
+
+/// Assignment of records, identified by their index, to an amount and a fee
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Selection {
+    /// A single record pays the amount and a distinct record pays the fee
+    Direct { amount: usize, fee: Option<usize> },
+    /// Two records must be joined to pay the amount. The fee record pays the fee of the join and
+    /// its change pays the fee of the following transaction
+    Join { records: (usize, usize), fee: Option<usize> },
+}
+
+/// Select the records paying an amount and a fee from a set of record balances. Direct selections
+/// are preferred over joins, and the smallest sufficient records are chosen so that large records
+/// are kept for large payments
+pub(crate) fn select_records(balances: &[u128], amount: u128, fee: u128) -> Option<Selection> {
+    // Find the smallest record holding at least a minimum balance, optionally excluding a record
+    let smallest = |minimum: u128, excluded: &[usize]| {
+        balances
+            .iter()
+            .enumerate()
+            .filter(|(index, balance)| **balance >= minimum && !excluded.contains(index))
+            .min_by_key(|(index, balance)| (**balance, *index))
+            .map(|(index, _)| index)
+    };
+
+    // Try to pay the amount and the fee with one record each. Taking the smallest record covering
+    // the amount first is optimal, as the records covering the larger of the two values also cover
+    // the smaller one
+    if let Some(amount_record) = smallest(amount, &[]) {
+        if fee == 0 {
+            return Some(Selection::Direct { amount: amount_record, fee: None });
+        }
+        if let Some(fee_record) = smallest(fee, &[amount_record]) {
+            return Some(Selection::Direct { amount: amount_record, fee: Some(fee_record) });
+        }
+    }
+
+    // Otherwise find the two records with the smallest combined balance covering the amount, leaving
+    // a distinct record that covers the fee of both the join and the following transaction
+    let mut fee_candidates =
+        (0..balances.len()).filter(|index| balances[*index] >= fee.saturating_mul(2)).collect::<Vec<_>>();
+    fee_candidates.sort_by_key(|index| (balances[*index], *index));
+    fee_candidates.truncate(FEE_CANDIDATES);
+
+    let mut best: Option<(u128, Selection)> = None;
+    for first in 0..balances.len() {
+        for second in first + 1..balances.len() {
+            let combined = balances[first].saturating_add(balances[second]);
+            if combined < amount || matches!(best, Some((best_combined, _)) if combined >= best_combined) {
+                continue;
+            }
+            let fee_record = match fee {
+                0 => None,
+                _ => match fee_candidates.iter().find(|index| **index != first && **index != second) {
+                    Some(index) => Some(*index),
+                    None => continue,
+                },
+            };
+            best = Some((combined, Selection::Join { records: (first, second), fee: fee_record }));
+        }
+    }
+    best.map(|(_, selection)| selection)
+}
+
+/// Coin selection for credits.aleo records and records of token programs
+#[wasm_bindgen]
+pub struct CoinSelection;
+
+#[wasm_bindgen]
+impl CoinSelection {
+    /// Select the credits records paying an amount and a fee. A record is never selected twice, and
+    /// records whose serial numbers are known to be spent are never selected
+    ///
+    /// When a single record covers the amount the result is `{ type: "direct", amountRecord, feeRecord }`.
+    /// Otherwise the result is `{ type: "join", joinRecords, feeRecord }`, meaning the two join records
+    /// must first be joined into a record covering the amount. In that case the fee record covers twice
+    /// the fee, paying the fee of the join and, with its change, the fee of the following transaction.
+    /// The fee record is undefined when the fee is zero
+    ///
+    /// @param {PrivateKey} private_key Private key of the account owning the records
+    /// @param {Array} records Array of credits record plaintext strings
+    /// @param {number} amount_credits Amount of credits to pay
+    /// @param {number} fee_credits Fee in credits to pay with a private fee record, or zero
+    /// @param {Array | undefined} spent_serial_numbers Serial numbers of records known to be spent
+    /// @returns {Object} Selected records
+    pub fn select(
+        private_key: &PrivateKey,
+        records: Array,
+        amount_credits: f64,
+        fee_credits: f64,
+        spent_serial_numbers: Option<Array>,
+    ) -> Result<Object, AleoError> {
+        let amount = credits_to_microcredits(amount_credits, "Amount")?;
+        if amount == 0 {
+            return Err(AleoError::invalid_input("Amount must be greater than zero"));
+        }
+        let fee = credits_to_microcredits(fee_credits, "Fee")?;
+
+        let candidates = unspent_records(private_key, records, &BalanceSpec::credits(), spent_serial_numbers)?;
+        let balances = candidates.iter().map(|(_, balance)| *balance).collect::<Vec<_>>();
+        let selection = select_records(&balances, amount, fee).ok_or_else(|| {
+            AleoError::insufficient_balance(format!(
+                "The unspent records holding {} microcredits cannot pay an amount of {amount} and a fee of {fee} microcredits",
+                balances.iter().sum::<u128>()
+            ))
+        })?;
+        selection_to_object(&candidates, selection)
+    }
+
+    /// Select the records of a token program paying an amount. The balance of every record is read
+    /// from the member described by the balance spec, and the same rules as `select` apply. Fees are
+    /// paid in credits, so fee records should be selected separately with `select`
+    ///
+    /// @param {PrivateKey} private_key Private key of the account owning the records
+    /// @param {Array} records Array of token record plaintext strings
+    /// @param {string} amount Amount of tokens to pay as a decimal integer
+    /// @param {BalanceSpec} spec Balance spec of the token records
+    /// @param {Array | undefined} spent_serial_numbers Serial numbers of records known to be spent
+    /// @returns {Object} Selected records
+    #[wasm_bindgen(js_name = selectToken)]
+    pub fn select_token(
+        private_key: &PrivateKey,
+        records: Array,
+        amount: &str,
+        spec: &BalanceSpec,
+        spent_serial_numbers: Option<Array>,
+    ) -> Result<Object, AleoError> {
+        let amount = amount.parse::<u128>().map_err(|_| AleoError::parse(format!("{amount} is an invalid amount")))?;
+        if amount == 0 {
+            return Err(AleoError::invalid_input("Amount must be greater than zero"));
+        }
+
+        let candidates = unspent_records(private_key, records, spec, spent_serial_numbers)?;
+        let balances = candidates.iter().map(|(_, balance)| *balance).collect::<Vec<_>>();
+        let selection = select_records(&balances, amount, 0).ok_or_else(|| {
+            AleoError::insufficient_balance(format!(
+                "The unspent {} records holding {} cannot pay an amount of {amount}",
+                spec.program_id(),
+                balances.iter().fold(0u128, |total, balance| total.saturating_add(*balance))
+            ))
+        })?;
+        selection_to_object(&candidates, selection)
+    }
+}
+
+// Collect the unspent records holding a balance, skipping duplicates so no record can be selected twice
+fn unspent_records(
+    private_key: &PrivateKey,
+    records: Array,
+    spec: &BalanceSpec,
+    spent_serial_numbers: Option<Array>,
+) -> Result<Vec<(RecordPlaintext, u128)>, AleoError> {
+    let mut known_serial_numbers: HashSet<String> = spent_serial_numbers
+        .map(|serial_numbers| serial_numbers.iter().filter_map(|serial_number| serial_number.as_string()).collect())
+        .unwrap_or_else(HashSet::new);
+
+    let (program_id, record_name) = (spec.program_id(), spec.record_name());
+    let mut candidates = Vec::new();
+    for record in records.iter() {
+        let record = record.as_string().ok_or_else(|| AleoError::invalid_input("Every record must be a string"))?;
+        let record = RecordPlaintext::from_string(&record)?;
+        let balance = match Balance::balance(&*record, spec) {
+            Ok(balance) if balance > 0 => balance,
+            _ => continue,
+        };
+        let serial_number = record.serial_number_string(private_key, &program_id, &record_name)?;
+        if known_serial_numbers.insert(serial_number) {
+            candidates.push((record, balance));
+        }
+    }
+    Ok(candidates)
+}
+
+// Convert an amount of credits into microcredits. The amount is rounded to the nearest microcredit, as
+// decimal amounts such as 2.01 credits are not exactly representable and would otherwise be truncated
+fn credits_to_microcredits(credits: f64, name: &str) -> Result<u128, AleoError> {
+    let microcredits = (credits * 1_000_000.0f64).round();
+    if !microcredits.is_finite() || microcredits < 0.0 || microcredits >= u64::MAX as f64 {
+        return Err(AleoError::invalid_input(format!(
+            "{name} must be a non-negative number of credits below {} microcredits",
+            u64::MAX
+        )));
+    }
+    Ok(microcredits as u128)
+}
+
+// Convert a selection of candidate records into the javascript object returned by the coin selection
+fn selection_to_object(candidates: &[(RecordPlaintext, u128)], selection: Selection) -> Result<Object, AleoError> {
+    let record = |index: usize| JsValue::from(candidates[index].0.clone());
+    let result = Object::new();
+    let fee_record = match selection {
+        Selection::Direct { amount, fee } => {
+            Reflect::set(&result, &"type".into(), &"direct".into())
+                .map_err(|_| AleoError::serialization("Failed to set property"))?;
+            Reflect::set(&result, &"amountRecord".into(), &record(amount))
+                .map_err(|_| AleoError::serialization("Failed to set property"))?;
+            fee
+        }
+        Selection::Join { records: (first, second), fee } => {
+            Reflect::set(&result, &"type".into(), &"join".into())
+                .map_err(|_| AleoError::serialization("Failed to set property"))?;
+            let join_records = Array::of2(&record(first), &record(second));
+            Reflect::set(&result, &"joinRecords".into(), &join_records)
+                .map_err(|_| AleoError::serialization("Failed to set property"))?;
+            fee
+        }
+    };
+    if let Some(fee_record) = fee_record {
+        Reflect::set(&result, &"feeRecord".into(), &record(fee_record))
+            .map_err(|_| AleoError::serialization("Failed to set property"))?;
+    }
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::native::{GroupNative, Uniform};
+
+    use wasm_bindgen_test::*;
+
+    const PRIVATE_KEY: &str = "APrivateKey1zkpDeRpuKmEtLNPdv57aFruPepeH1aGvTkEjBo8bqTzNUhE";
+    const OWNER: &str = "aleo1j7qxyunfldj2lp8hsvy7mw5k8zaqgjfyr72x2gh3x4ewgae8v5gscf5jh3";
+
+    fn credits_record(microcredits: u64) -> String {
+        let nonce: GroupNative = Uniform::rand(&mut rand::thread_rng());
+        format!("{{ owner: {OWNER}.private, microcredits: {microcredits}u64.private, _nonce: {nonce}.public }}")
+    }
+
+    #[wasm_bindgen_test]
+    fn test_select_records_direct() {
+        // Assert the smallest records covering the amount and fee are chosen
+        assert_eq!(select_records(&[50, 10, 30, 100], 25, 5), Some(Selection::Direct { amount: 2, fee: Some(1) }));
+        assert_eq!(select_records(&[50, 10, 30, 100], 25, 0), Some(Selection::Direct { amount: 2, fee: None }));
+
+        // Assert a record is never used for both the amount and the fee
+        assert_eq!(select_records(&[40, 40], 30, 30), Some(Selection::Direct { amount: 0, fee: Some(1) }));
+        assert_ne!(select_records(&[40], 30, 5), Some(Selection::Direct { amount: 0, fee: Some(0) }));
+        assert_eq!(select_records(&[20, 12, 11], 11, 11), Some(Selection::Direct { amount: 2, fee: Some(1) }));
+        assert_eq!(select_records(&[20, 6, 12], 5, 10), Some(Selection::Direct { amount: 1, fee: Some(2) }));
+    }
+
+    #[wasm_bindgen_test]
+    fn test_select_records_join() {
+        assert_eq!(select_records(&[20, 30, 15, 100], 120, 5), Some(Selection::Join { records: (1, 3), fee: Some(2) }));
+        assert_eq!(select_records(&[20, 30, 15], 40, 0), Some(Selection::Join { records: (0, 2), fee: None }));
+
+        // Assert the fee record covers the fee of the join and of the following transaction
+        assert_eq!(select_records(&[20, 30, 15], 40, 8), None);
+        assert_eq!(select_records(&[20, 30, 16], 40, 8), Some(Selection::Join { records: (0, 1), fee: Some(2) }));
+
+        assert_eq!(select_records(&[20, 30], 60, 0), None);
+        assert_eq!(select_records(&[], 1, 0), None);
+    }
+
+    #[wasm_bindgen_test]
+    fn test_coin_selection_skips_spent_and_duplicate_records() {
+        let private_key = PrivateKey::from_string(PRIVATE_KEY).unwrap();
+        let large = credits_record(5_000_000);
+        let small = credits_record(2_000_000);
+        let fee = credits_record(1_000_000);
+
+        let records = Array::of3(&large.as_str().into(), &small.as_str().into(), &fee.as_str().into());
+        let selection = CoinSelection::select(&private_key, records.clone(), 1.5, 0.5, None).unwrap();
+        let amount_record = Reflect::get(&selection, &"amountRecord".into()).unwrap();
+        assert_eq!(Reflect::get(&selection, &"type".into()).unwrap().as_string().unwrap(), "direct");
+        assert_eq!(amount_record.js_typeof().as_string().unwrap(), "object");
+
+        // Assert spent records are never selected
+        let spent = RecordPlaintext::from_string(&small)
+            .unwrap()
+            .serial_number_string(&private_key, "credits.aleo", "credits")
+            .unwrap();
+        let spent = Array::of1(&spent.into());
+        let selection = CoinSelection::select(&private_key, records.clone(), 1.5, 0.5, Some(spent.clone())).unwrap();
+        assert_eq!(Reflect::get(&selection, &"type".into()).unwrap().as_string().unwrap(), "direct");
+        assert!(CoinSelection::select(&private_key, records, 5.5, 0.5, Some(spent)).is_err());
+
+        // Assert duplicated records are only counted once
+        let duplicates = Array::of2(&large.as_str().into(), &large.as_str().into());
+        let error = CoinSelection::select(&private_key, duplicates, 1.0, 1.0, None).unwrap_err();
+        assert_eq!(error.code(), crate::ErrorCode::InsufficientBalance);
+        assert!(CoinSelection::select(&private_key, Array::new(), 0.0, 1.0, None).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_coin_selection_rounds_credits_to_microcredits() {
+        let private_key = PrivateKey::from_string(PRIVATE_KEY).unwrap();
+        assert_eq!(credits_to_microcredits(2.01, "Amount").unwrap(), 2_010_000);
+        assert_eq!(credits_to_microcredits(0.3, "Fee").unwrap(), 300_000);
+
+        // Assert a record one microcredit short of 2.01 credits cannot pay it
+        let short = Array::of1(&credits_record(2_009_999).into());
+        let error = CoinSelection::select(&private_key, short, 2.01, 0.0, None).unwrap_err();
+        assert_eq!(error.code(), crate::ErrorCode::InsufficientBalance);
+        let exact = Array::of1(&credits_record(2_010_000).into());
+        assert!(CoinSelection::select(&private_key, exact, 2.01, 0.0, None).is_ok());
+
+        // Assert amounts which are not a finite number of microcredits are rejected
+        for credits in [f64::NAN, f64::INFINITY, -1.0, 2e13] {
+            let records = Array::of1(&credits_record(2_010_000).into());
+            let error = CoinSelection::select(&private_key, records, credits, 0.0, None).unwrap_err();
+            assert_eq!(error.code(), crate::ErrorCode::InvalidInput);
+        }
+        let records = Array::of1(&credits_record(2_010_000).into());
+        assert!(CoinSelection::select(&private_key, records, 1.0, f64::NAN, None).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_coin_selection_selects_token_records() {
+        let private_key = PrivateKey::from_string(PRIVATE_KEY).unwrap();
+        let token_record = |amount: u128| {
+            let nonce: GroupNative = Uniform::rand(&mut rand::thread_rng());
+            format!("{{ owner: {OWNER}.private, amount: {amount}u128.private, _nonce: {nonce}.public }}")
+        };
+        let spec = BalanceSpec::new("token_test.aleo", "token", "amount", "u128").unwrap();
+        let records = Array::of3(&token_record(40).into(), &token_record(25).into(), &credits_record(1_000_000).into());
+
+        let selection = CoinSelection::select_token(&private_key, records.clone(), "30", &spec, None).unwrap();
+        assert_eq!(Reflect::get(&selection, &"type".into()).unwrap().as_string().unwrap(), "direct");
+        assert!(Reflect::get(&selection, &"feeRecord".into()).unwrap().is_undefined());
+        let selection = CoinSelection::select_token(&private_key, records.clone(), "60", &spec, None).unwrap();
+        assert_eq!(Reflect::get(&selection, &"type".into()).unwrap().as_string().unwrap(), "join");
+
+        // Assert the credits record is not counted towards the token balance
+        let error = CoinSelection::select_token(&private_key, records.clone(), "70", &spec, None).unwrap_err();
+        assert_eq!(error.code(), crate::ErrorCode::InsufficientBalance);
+        assert!(CoinSelection::select_token(&private_key, records, "ten", &spec, None).is_err());
+    }
+}
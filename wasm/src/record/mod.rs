@@ -14,8 +14,20 @@
 // You should have received a copy of the GNU General Public License
 // along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
 
+pub mod balance_spec;
+pub use balance_spec::*;
+
+pub mod coin_selection;
+pub use coin_selection::*;
+
 pub mod record_ciphertext;
 pub use record_ciphertext::*;
 
 pub mod record_plaintext;
 pub use record_plaintext::*;
+
+pub mod record_scanner;
+pub use record_scanner::*;
+
+pub mod record_view_key;
+pub use record_view_key::*;
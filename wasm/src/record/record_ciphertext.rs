@@ -14,10 +14,11 @@
 // You should have received a copy of the GNU General Public License
 // along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
 
-use super::RecordPlaintext;
-use crate::account::ViewKey;
+use super::{record_plaintext::visible_value, RecordPlaintext};
+use crate::{account::ViewKey, AleoError};
 
-use crate::types::native::RecordCiphertextNative;
+use crate::types::native::{FromBytes, Owner, RecordCiphertextNative, ToBytes};
+use js_sys::Object;
 use std::{ops::Deref, str::FromStr};
 use wasm_bindgen::prelude::*;
 
@@ -33,8 +34,25 @@ impl RecordCiphertext {
     /// @param {string} record String representation of a record ciphertext
     /// @returns {RecordCiphertext} Record ciphertext
     #[wasm_bindgen(js_name = fromString)]
-    pub fn from_string(record: &str) -> Result<RecordCiphertext, String> {
-        Self::from_str(record).map_err(|_| "The record ciphertext string provided was invalid".to_string())
+    pub fn from_string(record: &str) -> Result<RecordCiphertext, AleoError> {
+        Self::from_str(record).map_err(|_| AleoError::parse("The record ciphertext string provided was invalid"))
+    }
+
+    /// Create a record ciphertext from its byte representation
+    ///
+    /// @param {Uint8Array} bytes Byte representation of a record ciphertext
+    /// @returns {RecordCiphertext} Record ciphertext
+    #[wasm_bindgen(js_name = fromBytes)]
+    pub fn from_bytes(bytes: &[u8]) -> Result<RecordCiphertext, AleoError> {
+        Ok(Self(RecordCiphertextNative::from_bytes_le(bytes).map_err(AleoError::parse)?))
+    }
+
+    /// Returns the byte representation of the record ciphertext
+    ///
+    /// @returns {Uint8Array} Byte representation of the record ciphertext
+    #[wasm_bindgen(js_name = toBytes)]
+    pub fn to_bytes(&self) -> Result<Vec<u8>, AleoError> {
+        self.0.to_bytes_le().map_err(|_| AleoError::serialization("Failed to serialize record ciphertext"))
     }
 
     /// Return the string reprensentation of the record ciphertext
@@ -46,14 +64,33 @@ impl RecordCiphertext {
         self.0.to_string()
     }
 
+    /// Returns the nonce of the record
+    ///
+    /// @returns {string} Nonce of the record
+    pub fn nonce(&self) -> String {
+        self.0.nonce().to_string()
+    }
+
+    /// Returns the owner of the record. A private owner is returned as its ciphertext
+    ///
+    /// @returns {Object} Object holding the owner address or ciphertext as its value and the visibility of the owner
+    pub fn owner(&self) -> Result<Object, AleoError> {
+        match self.0.owner() {
+            Owner::Public(address) => visible_value(&address.to_string().into(), "public"),
+            Owner::Private(ciphertext) => visible_value(&ciphertext.to_string().into(), "private"),
+        }
+    }
+
     /// Decrypt the record ciphertext into plaintext using the view key. The record will only
     /// decrypt if the record was encrypted by the account corresponding to the view key
     ///
     /// @param {ViewKey} view_key View key used to decrypt the ciphertext
     /// @returns {RecordPlaintext} Record plaintext object
-    pub fn decrypt(&self, view_key: &ViewKey) -> Result<RecordPlaintext, String> {
+    pub fn decrypt(&self, view_key: &ViewKey) -> Result<RecordPlaintext, AleoError> {
         Ok(RecordPlaintext::from(
-            self.0.decrypt(view_key).map_err(|_| "Decryption failed - view key did not match record".to_string())?,
+            self.0
+                .decrypt(view_key)
+                .map_err(|_| AleoError::invalid_input("Decryption failed - view key did not match record"))?,
         ))
     }
 
@@ -67,6 +104,12 @@ impl RecordCiphertext {
     }
 }
 
+impl From<RecordCiphertextNative> for RecordCiphertext {
+    fn from(record: RecordCiphertextNative) -> Self {
+        Self(record)
+    }
+}
+
 impl FromStr for RecordCiphertext {
     type Err = anyhow::Error;
 
@@ -86,7 +129,12 @@ impl Deref for RecordCiphertext {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::types::{
+        native::{ScalarNative, Uniform},
+        Scalar,
+    };
 
+    use js_sys::Reflect;
     use wasm_bindgen_test::wasm_bindgen_test;
 
     const OWNER_PLAINTEXT: &str = r"{
@@ -112,8 +160,8 @@ mod tests {
     fn test_invalid_strings() {
         let invalid_bech32 = "record2qqj3a67efazf0awe09grqqg44htnh9vaw7l729vl309c972x7ldquqq2k2cax8s7qsqqyqtpgvqqyqsq4seyrzvfa98fkggzccqr68af8e9m0q8rzeqh8a8aqql3a854v58sgrygdv4jn9s8ckwfd48vujrmv0rtfasqh8ygn88ch34ftck8szspvfpsqqszqzvxx9t8s9g66teeepgxmvnw5ymgapcwt2lpy9d5eus580k08wpq544jcl437wjv206u5pxst6few9ll4yhufwldgpx80rlwq8nhssqywmfsd85skg564vqhm3gxsp8q6r30udmqxrxmxx2v8xycdg8pn5ps3dhfvv";
         assert_eq!(
-            RecordCiphertext::from_string("garbage").err(),
-            Some("The record ciphertext string provided was invalid".to_string())
+            RecordCiphertext::from_string("garbage").unwrap_err().message(),
+            "The record ciphertext string provided was invalid"
         );
         assert!(RecordCiphertext::from_string(invalid_bech32).is_err());
     }
@@ -121,19 +169,60 @@ mod tests {
     #[wasm_bindgen_test]
     fn test_decrypt() {
         let record = RecordCiphertext::from_string(OWNER_CIPHERTEXT).unwrap();
-        let view_key = ViewKey::from_string(OWNER_VIEW_KEY);
+        let view_key = ViewKey::from_string(OWNER_VIEW_KEY).unwrap();
         let plaintext = record.decrypt(&view_key).unwrap();
         assert_eq!(plaintext.to_string(), OWNER_PLAINTEXT);
-        let incorrect_view_key = ViewKey::from_string(NON_OWNER_VIEW_KEY);
+        let incorrect_view_key = ViewKey::from_string(NON_OWNER_VIEW_KEY).unwrap();
         assert!(record.decrypt(&incorrect_view_key).is_err());
     }
 
     #[wasm_bindgen_test]
     fn test_is_owner() {
         let record = RecordCiphertext::from_string(OWNER_CIPHERTEXT).unwrap();
-        let view_key = ViewKey::from_string(OWNER_VIEW_KEY);
+        let view_key = ViewKey::from_string(OWNER_VIEW_KEY).unwrap();
         assert!(record.is_owner(&view_key));
-        let incorrect_view_key = ViewKey::from_string(NON_OWNER_VIEW_KEY);
+        let incorrect_view_key = ViewKey::from_string(NON_OWNER_VIEW_KEY).unwrap();
         assert!(!record.is_owner(&incorrect_view_key));
     }
+
+    #[wasm_bindgen_test]
+    fn test_nonce_and_owner() {
+        let record = RecordCiphertext::from_string(OWNER_CIPHERTEXT).unwrap();
+        let plaintext = record.decrypt(&ViewKey::from_string(OWNER_VIEW_KEY).unwrap()).unwrap();
+        assert_eq!(record.nonce(), plaintext.nonce());
+
+        let owner = record.owner().unwrap();
+        assert_eq!(Reflect::get(&owner, &"visibility".into()).unwrap().as_string().unwrap(), "private");
+        assert!(Reflect::get(&owner, &"value".into()).unwrap().as_string().unwrap().starts_with("ciphertext1"));
+    }
+
+    #[wasm_bindgen_test]
+    fn test_to_and_from_bytes() {
+        let record = RecordCiphertext::from_string(OWNER_CIPHERTEXT).unwrap();
+        let recovered = RecordCiphertext::from_bytes(&record.to_bytes().unwrap()).unwrap();
+        assert_eq!(recovered.to_string(), OWNER_CIPHERTEXT);
+        assert!(RecordCiphertext::from_bytes(&[1, 2, 3]).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_encrypt_and_decrypt() {
+        let view_key = ViewKey::from_string(OWNER_VIEW_KEY).unwrap();
+        let randomizer: ScalarNative = Uniform::rand(&mut rand::thread_rng());
+        let randomizer = Scalar::from(randomizer);
+        let plaintext = RecordPlaintext::from_string(OWNER_PLAINTEXT).unwrap().with_randomizer(&randomizer).unwrap();
+        assert_ne!(plaintext.to_string(), OWNER_PLAINTEXT);
+
+        // Assert the encrypted record decrypts to the original record for its owner only
+        let ciphertext = plaintext.encrypt(&randomizer).unwrap();
+        assert_eq!(ciphertext.nonce(), plaintext.nonce());
+        assert!(ciphertext.is_owner(&view_key));
+        assert_eq!(ciphertext.decrypt(&view_key).unwrap().to_string(), plaintext.to_string());
+        assert!(ciphertext.decrypt(&ViewKey::from_string(NON_OWNER_VIEW_KEY).unwrap()).is_err());
+
+        // Assert a randomizer which does not correspond to the record nonce is rejected
+        let other_randomizer: ScalarNative = Uniform::rand(&mut rand::thread_rng());
+        let other_randomizer = Scalar::from(other_randomizer);
+        assert!(plaintext.encrypt(&other_randomizer).is_err());
+        assert!(RecordPlaintext::from_string(OWNER_PLAINTEXT).unwrap().encrypt(&randomizer).is_err());
+    }
 }
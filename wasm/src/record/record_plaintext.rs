@@ -14,9 +14,36 @@
 // You should have received a copy of the GNU General Public License
 // along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{account::PrivateKey, types::Field, Credits};
+use crate::{
+    account::{Address, GraphKey, PrivateKey},
+    programs::Program,
+    record::{BalanceSpec, RecordCiphertext},
+    types::{Field, Scalar},
+    AleoError,
+    Balance,
+    Credits,
+};
 
-use crate::types::native::{IdentifierNative, ProgramIDNative, RecordPlaintextNative};
+use crate::types::native::{
+    CurrentNetwork,
+    Entry,
+    EntryType,
+    GraphKeyNative,
+    IdentifierNative,
+    LiteralNative,
+    Network,
+    Owner,
+    PlaintextNative,
+    PlaintextType,
+    ProgramIDNative,
+    ProgramNative,
+    RecordPlaintextNative,
+    ScalarNative,
+    Uniform,
+};
+use indexmap::IndexMap;
+use js_sys::{Array, BigInt, Object, Reflect};
+use once_cell::sync::OnceCell;
 use std::{ops::Deref, str::FromStr};
 use wasm_bindgen::prelude::*;
 
@@ -28,25 +55,51 @@ pub struct RecordPlaintext(RecordPlaintextNative);
 #[wasm_bindgen]
 impl RecordPlaintext {
     #[wasm_bindgen]
-    pub fn commitment(&self, program_id: &str, record_name: &str) -> Result<Field, String> {
+    pub fn commitment(&self, program_id: &str, record_name: &str) -> Result<Field, AleoError> {
         Ok(Field::from(
             self.to_commitment(
                 &ProgramIDNative::from_str(program_id)
-                    .map_err(|_| format!("{program_id} is an invalid program name"))?,
+                    .map_err(|_| AleoError::parse(format!("{program_id} is an invalid program name")))?,
                 &IdentifierNative::from_str(record_name)
-                    .map_err(|_| format!("{record_name} is an invalid identifier"))?,
+                    .map_err(|_| AleoError::parse(format!("{record_name} is an invalid identifier")))?,
             )
-            .map_err(|e| e.to_string())?,
+            .map_err(AleoError::invalid_input)?,
         ))
     }
 
+    /// Create a record of a program from a javascript object holding the values of its entries. The
+    /// object is validated against the record members returned by `Program.getRecordMembers`, the
+    /// record is owned by the given address and a fresh random nonce is assigned to it
+    ///
+    /// Literals may be given as booleans, numbers, bigints or strings, with or without their type
+    /// suffix. Structs are given as objects and arrays as arrays
+    ///
+    /// @param {Program} program Program defining the record
+    /// @param {string} record_name Name of the record
+    /// @param {Address} owner Address of the owner of the record
+    /// @param {Object} members Object mapping the name of every entry of the record to its value
+    /// @returns {RecordPlaintext} Record plaintext
+    ///
+    /// @example
+    ///
+    /// const record = RecordPlaintext.fromObject(program, "token", owner, { amount: 100n, metadata: { id: "5field" } });
+    #[wasm_bindgen(js_name = fromObject)]
+    pub fn from_object(
+        program: &Program,
+        record_name: &str,
+        owner: &Address,
+        members: &Object,
+    ) -> Result<RecordPlaintext, AleoError> {
+        Self::from_members(program, record_name, owner, members)
+    }
+
     /// Return a record plaintext from a string.
     ///
     /// @param {string} record String representation of a plaintext representation of an Aleo record
     /// @returns {RecordPlaintext} Record plaintext
     #[wasm_bindgen(js_name = fromString)]
-    pub fn from_string(record: &str) -> Result<RecordPlaintext, String> {
-        Self::from_str(record).map_err(|_| "The record plaintext string provided was invalid".into())
+    pub fn from_string(record: &str) -> Result<RecordPlaintext, AleoError> {
+        Self::from_str(record).map_err(|_| AleoError::parse("The record plaintext string provided was invalid"))
     }
 
     /// Returns the record plaintext string
@@ -65,6 +118,19 @@ impl RecordPlaintext {
         self.0.microcredits().unwrap_or(0)
     }
 
+    /// Returns the balance held by the record at the location described by a balance spec, for
+    /// example the amount of a token record
+    ///
+    /// @param {BalanceSpec} spec Balance spec of the record
+    /// @returns {BigInt} Balance of the record
+    ///
+    /// @example
+    ///
+    /// const balance = record.balance(new BalanceSpec("token_test.aleo", "token", "amount", "u64"));
+    pub fn balance(&self, spec: &BalanceSpec) -> Result<BigInt, AleoError> {
+        Ok(BigInt::from(Balance::balance(&self.0, spec)?))
+    }
+
     /// Returns the nonce of the record. This can be used to uniquely identify a record.
     ///
     /// @returns {string} Nonce of the record
@@ -73,6 +139,92 @@ impl RecordPlaintext {
         self.0.nonce().to_string()
     }
 
+    /// Returns the owner of the record
+    ///
+    /// @returns {Object} Object holding the address of the owner as its value and the visibility of the owner
+    pub fn owner(&self) -> Result<Object, AleoError> {
+        let (address, visibility) = match self.0.owner() {
+            Owner::Public(address) => (address.to_string(), "public"),
+            Owner::Private(PlaintextNative::Literal(LiteralNative::Address(address), ..)) => {
+                (address.to_string(), "private")
+            }
+            Owner::Private(_) => return Err(AleoError::invalid_input("The record owner is not an address")),
+        };
+        visible_value(&address.into(), visibility)
+    }
+
+    /// Get a member of the record by its path. Members of structs are selected with `.` and
+    /// elements of arrays with `[index]`, for example `token.balances[1].amount`
+    ///
+    /// Booleans and integers of up to 32 bits are returned as javascript booleans and numbers,
+    /// 64 and 128 bit integers as bigints so they are not truncated, and every other
+    /// literal as its Aleo string representation. Structs and arrays are returned as javascript
+    /// objects and arrays
+    ///
+    /// @param {string} path Path of the member
+    /// @returns {Object} Object holding the member as its value and the visibility of the record entry
+    #[wasm_bindgen(js_name = getMember)]
+    pub fn get_member(&self, path: &str) -> Result<Object, AleoError> {
+        match path {
+            "owner" => self.owner(),
+            "_nonce" => visible_value(&self.nonce().into(), "public"),
+            _ => {
+                let (plaintext, visibility) = find_member(&self.0, path)?;
+                visible_value(&plaintext_to_js(plaintext)?, visibility)
+            }
+        }
+    }
+
+    /// Returns the record as a javascript object mapping the owner, every entry and the nonce of the
+    /// record to an object holding its value and visibility, with values converted as in `getMember`
+    ///
+    /// @returns {Object} Javascript object representation of the record
+    #[wasm_bindgen(js_name = toJSON)]
+    pub fn to_json(&self) -> Result<Object, AleoError> {
+        let record = Object::new();
+        Reflect::set(&record, &"owner".into(), &self.owner()?)
+            .map_err(|_| AleoError::serialization("Failed to set property"))?;
+        for (identifier, entry) in self.0.data() {
+            let (plaintext, visibility) = match entry {
+                Entry::Constant(plaintext) => (plaintext, "constant"),
+                Entry::Public(plaintext) => (plaintext, "public"),
+                Entry::Private(plaintext) => (plaintext, "private"),
+            };
+            let value = visible_value(&plaintext_to_js(plaintext)?, visibility)?;
+            Reflect::set(&record, &identifier.to_string().into(), &value)
+                .map_err(|_| AleoError::serialization("Failed to set property"))?;
+        }
+        let nonce = visible_value(&self.nonce().into(), "public")?;
+        Reflect::set(&record, &"_nonce".into(), &nonce)
+            .map_err(|_| AleoError::serialization("Failed to set property"))?;
+        Ok(record)
+    }
+
+    /// Encrypt the record to its owner. The randomizer must be the one the nonce of the record was
+    /// derived from, see `withRandomizer` to assign the nonce of a chosen randomizer to a record
+    ///
+    /// @param {Scalar} randomizer Randomizer of the record nonce
+    /// @returns {RecordCiphertext} Record ciphertext
+    pub fn encrypt(&self, randomizer: &Scalar) -> Result<RecordCiphertext, AleoError> {
+        let ciphertext = self.0.encrypt(randomizer.clone().into()).map_err(|_| {
+            AleoError::invalid_input("The randomizer provided does not correspond to the nonce of the record")
+        })?;
+        Ok(RecordCiphertext::from(ciphertext))
+    }
+
+    /// Returns a copy of the record whose nonce is derived from a randomizer, so that the record can
+    /// be encrypted with `encrypt`
+    ///
+    /// @param {Scalar} randomizer Randomizer of the record nonce
+    /// @returns {RecordPlaintext} Record plaintext with the new nonce
+    #[wasm_bindgen(js_name = withRandomizer)]
+    pub fn with_randomizer(&self, randomizer: &Scalar) -> Result<RecordPlaintext, AleoError> {
+        let nonce = CurrentNetwork::g_scalar_multiply(&randomizer.clone().into());
+        let record = RecordPlaintextNative::from_plaintext(self.0.owner().clone(), self.0.data().clone(), nonce)
+            .map_err(AleoError::invalid_input)?;
+        Ok(Self(record))
+    }
+
     /// Attempt to get the serial number of a record to determine whether or not is has been spent
     ///
     /// @param {PrivateKey} private_key Private key of the account that owns the record
@@ -85,13 +237,139 @@ impl RecordPlaintext {
         private_key: &PrivateKey,
         program_id: &str,
         record_name: &str,
-    ) -> Result<String, String> {
+    ) -> Result<String, AleoError> {
         let commitment = self.commitment(program_id, record_name)?;
 
         let serial_number = RecordPlaintextNative::serial_number(private_key.into(), commitment.into())
-            .map_err(|_| "Serial number derivation failed".to_string())?;
+            .map_err(|_| AleoError::invalid_input("Serial number derivation failed"))?;
         Ok(serial_number.to_string())
     }
+
+    /// Get the tag of the record. The tag is included in the transition that spends the record, so
+    /// it can be used to determine whether or not a record has been spent without the private key
+    ///
+    /// @param {GraphKey} graph_key Graph key of the account that owns the record
+    /// @param {string} program_id Program ID of the program that the record is associated with
+    /// @param {string} record_name Name of the record
+    /// @returns {Field} Tag of the record
+    pub fn tag(&self, graph_key: &GraphKey, program_id: &str, record_name: &str) -> Result<Field, AleoError> {
+        let commitment = self.commitment(program_id, record_name)?;
+
+        let tag = RecordPlaintextNative::tag(GraphKeyNative::from(graph_key.clone()), commitment.into())
+            .map_err(|_| AleoError::invalid_input("Tag derivation failed"))?;
+        Ok(Field::from(tag))
+    }
+}
+
+/// Segment of the path of a record member
+enum PathSegment {
+    Member(String),
+    Index(usize),
+}
+
+// Split a path such as `token.balances[1].amount` into member names and array indices
+pub(crate) fn parse_member_path(path: &str) -> Result<Vec<PathSegment>, AleoError> {
+    let invalid_path = || AleoError::parse(format!("{path} is not a valid member path"));
+    let mut segments = Vec::new();
+    for part in path.split('.') {
+        let (name, mut indices) = part.split_at(part.find('[').unwrap_or(part.len()));
+        if name.is_empty() {
+            return Err(invalid_path());
+        }
+        segments.push(PathSegment::Member(name.to_string()));
+        while let Some(rest) = indices.strip_prefix('[') {
+            let end = rest.find(']').ok_or_else(invalid_path)?;
+            segments.push(PathSegment::Index(rest[..end].parse::<usize>().map_err(|_| invalid_path())?));
+            indices = &rest[end + 1..];
+        }
+        if !indices.is_empty() {
+            return Err(invalid_path());
+        }
+    }
+    Ok(segments)
+}
+
+// Find a member of the data of a record by its path, along with the visibility of its entry
+pub(crate) fn find_member<'a>(
+    record: &'a RecordPlaintextNative,
+    path: &str,
+) -> Result<(&'a PlaintextNative, &'static str), AleoError> {
+    let invalid_path = || AleoError::invalid_input(format!("The member {path} does not exist in the record"));
+    let mut segments = parse_member_path(path)?.into_iter();
+    let entry_name = match segments.next() {
+        Some(PathSegment::Member(name)) => name,
+        _ => return Err(invalid_path()),
+    };
+
+    let identifier = IdentifierNative::from_str(&entry_name).map_err(|_| invalid_path())?;
+    let (mut plaintext, visibility) = match record.data().get(&identifier).ok_or_else(invalid_path)? {
+        Entry::Constant(plaintext) => (plaintext, "constant"),
+        Entry::Public(plaintext) => (plaintext, "public"),
+        Entry::Private(plaintext) => (plaintext, "private"),
+    };
+    for segment in segments {
+        plaintext = match (segment, plaintext) {
+            (PathSegment::Member(name), PlaintextNative::Struct(members, ..)) => {
+                let identifier = IdentifierNative::from_str(&name).map_err(|_| invalid_path())?;
+                members.get(&identifier).ok_or_else(invalid_path)?
+            }
+            (PathSegment::Index(index), PlaintextNative::Array(elements, ..)) => {
+                elements.get(index).ok_or_else(invalid_path)?
+            }
+            _ => return Err(invalid_path()),
+        };
+    }
+    Ok((plaintext, visibility))
+}
+
+// Wrap a value in an object recording the visibility of the record entry it belongs to
+pub(crate) fn visible_value(value: &JsValue, visibility: &str) -> Result<Object, AleoError> {
+    let object = Object::new();
+    Reflect::set(&object, &"value".into(), value).map_err(|_| AleoError::serialization("Failed to set property"))?;
+    Reflect::set(&object, &"visibility".into(), &visibility.into())
+        .map_err(|_| AleoError::serialization("Failed to set property"))?;
+    Ok(object)
+}
+
+// Convert a plaintext into a javascript value
+pub(crate) fn plaintext_to_js(plaintext: &PlaintextNative) -> Result<JsValue, AleoError> {
+    Ok(match plaintext {
+        PlaintextNative::Literal(literal, ..) => literal_to_js(literal),
+        PlaintextNative::Struct(members, ..) => {
+            let object = Object::new();
+            for (name, member) in members {
+                Reflect::set(&object, &name.to_string().into(), &plaintext_to_js(member)?)
+                    .map_err(|_| AleoError::serialization("Failed to set property"))?;
+            }
+            object.into()
+        }
+        PlaintextNative::Array(elements, ..) => {
+            let array = Array::new_with_length(elements.len() as u32);
+            for (index, element) in elements.iter().enumerate() {
+                array.set(index as u32, plaintext_to_js(element)?);
+            }
+            array.into()
+        }
+    })
+}
+
+// Convert a literal into a javascript value. Integers wider than 32 bits are converted to bigints
+// as they cannot be represented exactly by javascript numbers
+fn literal_to_js(literal: &LiteralNative) -> JsValue {
+    match literal {
+        LiteralNative::Boolean(value) => JsValue::from_bool(**value),
+        LiteralNative::I8(value) => JsValue::from(**value),
+        LiteralNative::I16(value) => JsValue::from(**value),
+        LiteralNative::I32(value) => JsValue::from(**value),
+        LiteralNative::U8(value) => JsValue::from(**value),
+        LiteralNative::U16(value) => JsValue::from(**value),
+        LiteralNative::U32(value) => JsValue::from(**value),
+        LiteralNative::I64(value) => BigInt::from(**value).into(),
+        LiteralNative::I128(value) => BigInt::from(**value).into(),
+        LiteralNative::U64(value) => BigInt::from(**value).into(),
+        LiteralNative::U128(value) => BigInt::from(**value).into(),
+        _ => JsValue::from_str(&literal.to_string()),
+    }
 }
 
 impl From<RecordPlaintextNative> for RecordPlaintext {
@@ -108,6 +386,61 @@ impl FromStr for RecordPlaintext {
     }
 }
 
+impl RecordPlaintext {
+    /// Create a record of a program from a javascript object holding the values of its entries, as
+    /// described in `fromObject`
+    pub(crate) fn from_members(
+        program: &ProgramNative,
+        record_name: &str,
+        owner: &Address,
+        members: &Object,
+    ) -> Result<RecordPlaintext, AleoError> {
+        let record_id = IdentifierNative::from_str(record_name)
+            .map_err(|_| AleoError::parse(format!("{record_name} is an invalid identifier")))?;
+        let record_type = program
+            .get_record(&record_id)
+            .map_err(|_| AleoError::invalid_input(format!("record {record_name} not found in {}", program.id())))?;
+
+        // Reject members which are not entries of the record
+        for key in Object::keys(members).iter().filter_map(|key| key.as_string()) {
+            if !record_type.entries().keys().any(|entry| entry.to_string() == key) {
+                return Err(AleoError::invalid_input(format!("{key} is not an entry of record {record_name}")));
+            }
+        }
+
+        let mut data = IndexMap::with_capacity(record_type.entries().len());
+        for (entry_name, entry_type) in record_type.entries() {
+            let name = entry_name.to_string();
+            let value = Reflect::get(members, &name.as_str().into())
+                .ok()
+                .filter(|value| !value.is_undefined())
+                .ok_or_else(|| AleoError::invalid_input(format!("{name} is missing")))?;
+            let plaintext = |plaintext_type: &PlaintextType<CurrentNetwork>| {
+                Program::plaintext_from_js(program, plaintext_type, &value, &name)
+            };
+            let entry = match entry_type {
+                EntryType::Constant(plaintext_type) => Entry::Constant(plaintext(plaintext_type)?),
+                EntryType::Public(plaintext_type) => Entry::Public(plaintext(plaintext_type)?),
+                EntryType::Private(plaintext_type) => Entry::Private(plaintext(plaintext_type)?),
+            };
+            data.insert(*entry_name, entry);
+        }
+
+        let owner = if record_type.owner().is_public() {
+            Owner::Public(**owner)
+        } else {
+            Owner::Private(PlaintextNative::Literal(LiteralNative::Address(**owner), OnceCell::new()))
+        };
+
+        // Sample a randomizer and derive the nonce of the record
+        let randomizer: ScalarNative = Uniform::rand(&mut rand::thread_rng());
+        let nonce = CurrentNetwork::g_scalar_multiply(&randomizer);
+
+        let record = RecordPlaintextNative::from_plaintext(owner, data, nonce).map_err(AleoError::invalid_input)?;
+        Ok(Self(record))
+    }
+}
+
 impl Deref for RecordPlaintext {
     type Target = RecordPlaintextNative;
 
@@ -119,7 +452,12 @@ impl Deref for RecordPlaintext {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{
+        account::ViewKey,
+        types::native::{CurrentAleo, FieldNative, Input, Output, PrivateKeyNative, ProcessNative, ViewKeyNative},
+    };
 
+    use snarkvm_console::prelude::TestRng;
     use wasm_bindgen_test::wasm_bindgen_test;
 
     const RECORD: &str = r"{
@@ -128,18 +466,158 @@ mod tests {
   _nonce: 3077450429259593211617823051143573281856129402760267155982965992208217472983group.public
 }";
 
+    const TOKEN_RECORD: &str = r"{
+  owner: aleo1j7qxyunfldj2lp8hsvy7mw5k8zaqgjfyr72x2gh3x4ewgae8v5gscf5jh3.private,
+  amount: 100u64.private,
+  level: 3u8.public,
+  token: {
+    id: 5field.private,
+    flags: [
+      true.private,
+      false.private
+    ]
+  },
+  _nonce: 3077450429259593211617823051143573281856129402760267155982965992208217472983group.public
+}";
+
+    const TOKEN_PROGRAM: &str = r"program token_test.aleo;
+
+struct metadata:
+    id as field;
+    flags as [boolean; 2u32];
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+    level as u8.public;
+    token as metadata.private;
+
+function mint:
+    input r0 as u64.private;
+    output r0 as u64.private;
+";
+
+    const SPEND_PROGRAM: &str = r"program spend_test.aleo;
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+
+function mint:
+    input r0 as address.private;
+    input r1 as u64.private;
+    cast r0 r1 into r2 as token.record;
+    output r2 as token.record;
+
+function burn:
+    input r0 as token.record;
+    output r0.amount as u64.private;
+";
+
     #[wasm_bindgen_test]
     fn test_to_and_from_string() {
         let record = RecordPlaintext::from_string(RECORD).unwrap();
         assert_eq!(record.to_string(), RECORD);
     }
 
+    #[wasm_bindgen_test]
+    fn test_get_member() {
+        let record = RecordPlaintext::from_string(TOKEN_RECORD).unwrap();
+        let get = |path: &str| -> (JsValue, String) {
+            let member = record.get_member(path).unwrap();
+            let value = Reflect::get(&member, &"value".into()).unwrap();
+            (value, Reflect::get(&member, &"visibility".into()).unwrap().as_string().unwrap())
+        };
+
+        let (owner, visibility) = get("owner");
+        assert_eq!(owner.as_string().unwrap(), "aleo1j7qxyunfldj2lp8hsvy7mw5k8zaqgjfyr72x2gh3x4ewgae8v5gscf5jh3");
+        assert_eq!(visibility, "private");
+        let (amount, visibility) = get("amount");
+        assert_eq!(amount, JsValue::from(BigInt::from(100u64)));
+        assert_eq!(visibility, "private");
+        let (level, visibility) = get("level");
+        assert_eq!(level.as_f64().unwrap(), 3.0);
+        assert_eq!(visibility, "public");
+        assert_eq!(get("token.id").0.as_string().unwrap(), "5field");
+        assert!(!get("token.flags[1]").0.as_bool().unwrap());
+        assert!(Array::is_array(&get("token.flags").0));
+
+        // Assert that paths which do not exist in the record are rejected
+        assert!(record.get_member("missing").is_err());
+        assert!(record.get_member("token.flags[2]").is_err());
+        assert!(record.get_member("amount.value").is_err());
+        assert!(record.get_member("token..id").is_err());
+        assert!(record.get_member("token.flags[x]").is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_to_json() {
+        let record = RecordPlaintext::from_string(TOKEN_RECORD).unwrap();
+        let json = record.to_json().unwrap();
+        let keys = Object::keys(&json).iter().map(|key| key.as_string().unwrap()).collect::<Vec<_>>();
+        assert_eq!(keys, vec!["owner", "amount", "level", "token", "_nonce"]);
+
+        let token = Reflect::get(&Reflect::get(&json, &"token".into()).unwrap(), &"value".into()).unwrap();
+        assert_eq!(Reflect::get(&token, &"id".into()).unwrap().as_string().unwrap(), "5field");
+        let nonce = Reflect::get(&json, &"_nonce".into()).unwrap();
+        assert_eq!(Reflect::get(&nonce, &"value".into()).unwrap().as_string().unwrap(), record.nonce());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_from_object() {
+        let program = Program::from_string(TOKEN_PROGRAM).unwrap();
+        let owner = Address::from_string("aleo1j7qxyunfldj2lp8hsvy7mw5k8zaqgjfyr72x2gh3x4ewgae8v5gscf5jh3").unwrap();
+        let members = Object::new();
+        let token = Object::new();
+        let flags = Array::of2(&JsValue::TRUE, &JsValue::FALSE);
+        Reflect::set(&token, &"id".into(), &"5field".into()).unwrap();
+        Reflect::set(&token, &"flags".into(), &flags).unwrap();
+        Reflect::set(&members, &"amount".into(), &JsValue::from_str("100")).unwrap();
+        Reflect::set(&members, &"level".into(), &JsValue::from(3)).unwrap();
+        Reflect::set(&members, &"token".into(), &token).unwrap();
+
+        let record = RecordPlaintext::from_object(&program, "token", &owner, &members).unwrap();
+        let expected = RecordPlaintext::from_string(TOKEN_RECORD).unwrap();
+        assert!(record.0.owner() == expected.0.owner());
+        assert!(record.0.data() == expected.0.data());
+        assert_ne!(record.nonce(), expected.nonce());
+
+        // Assert that objects which do not match the record members are rejected
+        Reflect::set(&members, &"level".into(), &JsValue::from(256)).unwrap();
+        assert!(RecordPlaintext::from_object(&program, "token", &owner, &members).is_err());
+        Reflect::set(&members, &"level".into(), &JsValue::from(3)).unwrap();
+        Reflect::set(&members, &"extra".into(), &JsValue::from(3)).unwrap();
+        assert!(RecordPlaintext::from_object(&program, "token", &owner, &members).is_err());
+        Reflect::delete_property(&members, &"extra".into()).unwrap();
+        Reflect::delete_property(&members, &"amount".into()).unwrap();
+        assert!(RecordPlaintext::from_object(&program, "token", &owner, &members).is_err());
+        assert!(RecordPlaintext::from_object(&program, "missing", &owner, &members).is_err());
+    }
+
     #[wasm_bindgen_test]
     fn test_microcredits_from_string() {
         let record = RecordPlaintext::from_string(RECORD).unwrap();
         assert_eq!(record.microcredits(), 1500000000000000);
     }
 
+    #[wasm_bindgen_test]
+    fn test_balance() {
+        let record = RecordPlaintext::from_string(TOKEN_RECORD).unwrap();
+        let spec = BalanceSpec::new("token_test.aleo", "token", "amount", "u64").unwrap();
+        assert_eq!(Balance::balance(&record.0, &spec).unwrap(), 100);
+        let spec = BalanceSpec::new("token_test.aleo", "token", "level", "u8").unwrap();
+        assert_eq!(Balance::balance(&record.0, &spec).unwrap(), 3);
+
+        // Assert that members which are missing or of another type are rejected
+        let spec = BalanceSpec::new("token_test.aleo", "token", "amount", "u128").unwrap();
+        assert!(Balance::balance(&record.0, &spec).is_err());
+        let spec = BalanceSpec::new("token_test.aleo", "token", "token.id", "u64").unwrap();
+        assert!(Balance::balance(&record.0, &spec).is_err());
+        assert!(Balance::balance(&record.0, &BalanceSpec::credits()).is_err());
+        assert_eq!(record.balance(&BalanceSpec::credits()).unwrap_err().code(), crate::ErrorCode::InvalidInput);
+        assert_eq!(record.microcredits(), 0);
+    }
+
     #[wasm_bindgen_test]
     fn test_serial_number() {
         let pk = PrivateKey::from_string("APrivateKey1zkpDeRpuKmEtLNPdv57aFruPepeH1aGvTkEjBo8bqTzNUhE").unwrap();
@@ -162,6 +640,58 @@ mod tests {
         assert_eq!(expected_sn, record.serial_number_string(&pk, program_id, record_name).unwrap());
     }
 
+    #[wasm_bindgen_test]
+    fn test_tag() {
+        let mut rng = TestRng::default();
+        let pk = PrivateKeyNative::new(&mut rng).unwrap();
+        let view_key = ViewKeyNative::try_from(pk).unwrap();
+        let owner = view_key.to_address().to_string();
+        let program = ProgramNative::from_str(SPEND_PROGRAM).unwrap();
+        let mut process = ProcessNative::load_web().unwrap();
+        process.add_program(&program).unwrap();
+
+        // Mint a record to the account so that it has a valid nonce
+        let authorization = process
+            .authorize::<CurrentAleo, _>(
+                &pk,
+                "spend_test.aleo",
+                "mint",
+                [owner.as_str(), "100u64"].into_iter(),
+                &mut rng,
+            )
+            .unwrap();
+        let transition = authorization.transitions().values().next().unwrap().clone();
+        let record = match &transition.outputs()[0] {
+            Output::Record(_, _, Some(ciphertext)) => RecordPlaintext::from(ciphertext.decrypt(&view_key).unwrap()),
+            _ => panic!("Expected a record output"),
+        };
+
+        // Spend the record and assert the tag matches the tag of the transition input
+        let input = record.to_string();
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&pk, "spend_test.aleo", "burn", [input.as_str()].into_iter(), &mut rng)
+            .unwrap();
+        let transition = authorization.transitions().values().next().unwrap().clone();
+        let expected = match &transition.inputs()[0] {
+            Input::Record(_, tag) => *tag,
+            _ => panic!("Expected a record input"),
+        };
+        let graph_key = GraphKey::from_view_key(&ViewKey::from(view_key)).unwrap();
+        let tag = record.tag(&graph_key, "spend_test.aleo", "token").unwrap();
+        assert_eq!(FieldNative::from(tag.clone()), expected);
+        assert_eq!(
+            tag,
+            record
+                .tag(&GraphKey::from_private_key(&PrivateKey::from(pk)).unwrap(), "spend_test.aleo", "token")
+                .unwrap()
+        );
+
+        // Assert the tag is bound to the graph key and the commitment of the record
+        let other_graph_key = GraphKey::from_private_key(&PrivateKey::new()).unwrap();
+        assert_ne!(tag, record.tag(&other_graph_key, "spend_test.aleo", "token").unwrap());
+        assert_ne!(tag, record.tag(&graph_key, "credits.aleo", "credits").unwrap());
+    }
+
     #[wasm_bindgen_test]
     fn test_serial_number_invalid_program_id_returns_err_string() {
         let pk = PrivateKey::from_string("APrivateKey1zkpDeRpuKmEtLNPdv57aFruPepeH1aGvTkEjBo8bqTzNUhE").unwrap();
@@ -184,8 +714,8 @@ mod tests {
     fn test_bad_inputs_to_from_string() {
         let invalid_bech32 = "{ owner: aleo2d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, microcredits: 99u64.public, _nonce: 0group.public }";
         assert_eq!(
-            RecordPlaintext::from_string("string").err(),
-            Some("The record plaintext string provided was invalid".into())
+            RecordPlaintext::from_string("string").unwrap_err().message(),
+            "The record plaintext string provided was invalid"
         );
         assert!(RecordPlaintext::from_string(invalid_bech32).is_err());
     }
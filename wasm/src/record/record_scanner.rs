@@ -0,0 +1,336 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::RecordPlaintext;
+use crate::{account::ViewKey, thread_pool, AleoError};
+
+use crate::types::native::{
+    BlockNative,
+    FieldNative,
+    Output,
+    ProgramIDNative,
+    ProgramNative,
+    RecordCiphertextNative,
+    RecordPlaintextNative,
+    TransactionNative,
+    TransitionNative,
+    ValueType,
+    ViewKeyNative,
+};
+use indexmap::IndexMap;
+use js_sys::{Array, Object, Reflect};
+use rayon::prelude::*;
+use std::str::FromStr;
+use wasm_bindgen::prelude::*;
+
+/// Transition output a scanned record was found in
+struct RecordSource {
+    commitment: FieldNative,
+    program: String,
+    record_name: Option<String>,
+    transition_id: String,
+    function: String,
+    output_index: usize,
+}
+
+/// Record owned by the view key of a scan
+struct OwnedRecord {
+    record: RecordPlaintextNative,
+    source: Option<RecordSource>,
+}
+
+/// Scans record ciphertexts, transactions and blocks for the records owned by a view key. Ownership
+/// is checked in parallel on the thread pool started by `initThreadPool`
+#[wasm_bindgen]
+pub struct RecordScanner;
+
+#[wasm_bindgen]
+impl RecordScanner {
+    /// Find and decrypt the records owned by a view key in a batch of record ciphertexts
+    ///
+    /// @param {ViewKey} view_key View key of the account to scan for
+    /// @param {Array} ciphertexts Array of record ciphertext strings
+    /// @returns {Array} Array of { record } objects holding the owned records as RecordPlaintext objects
+    #[wasm_bindgen(js_name = scanCiphertexts)]
+    pub async fn scan_ciphertexts(view_key: &ViewKey, ciphertexts: Array) -> Result<Array, AleoError> {
+        let view_key = **view_key;
+        let ciphertexts = strings_from_array(&ciphertexts, "record ciphertext")?;
+        let records = thread_pool::spawn(move || scan_record_ciphertexts(&view_key, &ciphertexts)).await?;
+        owned_records_to_array(records)
+    }
+
+    /// Find and decrypt the records owned by a view key in the outputs of a batch of transactions
+    ///
+    /// @param {ViewKey} view_key View key of the account to scan for
+    /// @param {Array} transactions Array of transaction JSON strings
+    /// @param {Object | undefined} programs Object mapping program ids to the source code of programs
+    /// whose record names should be resolved. The names of credits.aleo records are always resolved
+    /// @returns {Array} Array of { record, commitment, program, recordName, transitionId, function,
+    /// outputIndex } objects describing the owned records
+    #[wasm_bindgen(js_name = scanTransactions)]
+    pub async fn scan_transactions(
+        view_key: &ViewKey,
+        transactions: Array,
+        programs: Option<Object>,
+    ) -> Result<Array, AleoError> {
+        let view_key = **view_key;
+        let transactions = strings_from_array(&transactions, "transaction")?;
+        let programs = programs_from_object(programs)?;
+        let records = thread_pool::spawn(move || {
+            let transactions = transactions
+                .par_iter()
+                .map(|transaction| {
+                    TransactionNative::from_str(transaction)
+                        .map_err(|_| AleoError::parse(format!("{transaction} is not a valid transaction")))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            let transitions =
+                transactions.iter().flat_map(|transaction| transaction.transitions().cloned()).collect::<Vec<_>>();
+            scan_transitions(&view_key, &transitions, &programs)
+        })
+        .await?;
+        owned_records_to_array(records)
+    }
+
+    /// Find and decrypt the records owned by a view key in the transactions of a batch of blocks
+    ///
+    /// @param {ViewKey} view_key View key of the account to scan for
+    /// @param {Array} blocks Array of block JSON strings
+    /// @param {Object | undefined} programs Object mapping program ids to the source code of programs
+    /// whose record names should be resolved. The names of credits.aleo records are always resolved
+    /// @returns {Array} Array of { record, commitment, program, recordName, transitionId, function,
+    /// outputIndex } objects describing the owned records
+    #[wasm_bindgen(js_name = scanBlocks)]
+    pub async fn scan_blocks(view_key: &ViewKey, blocks: Array, programs: Option<Object>) -> Result<Array, AleoError> {
+        let view_key = **view_key;
+        let blocks = strings_from_array(&blocks, "block")?;
+        let programs = programs_from_object(programs)?;
+        let records = thread_pool::spawn(move || {
+            let blocks = blocks
+                .par_iter()
+                .map(|block| {
+                    BlockNative::from_str(block).map_err(|_| AleoError::parse(format!("{block} is not a valid block")))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            let transitions = blocks.iter().flat_map(|block| block.transitions().cloned()).collect::<Vec<_>>();
+            scan_transitions(&view_key, &transitions, &programs)
+        })
+        .await?;
+        owned_records_to_array(records)
+    }
+}
+
+// Decrypt the record ciphertexts owned by a view key
+fn scan_record_ciphertexts(view_key: &ViewKeyNative, ciphertexts: &[String]) -> Result<Vec<OwnedRecord>, AleoError> {
+    ciphertexts
+        .par_iter()
+        .filter_map(|ciphertext| match RecordCiphertextNative::from_str(ciphertext) {
+            Ok(record) if record.is_owner(view_key) => Some(
+                record
+                    .decrypt(view_key)
+                    .map(|record| OwnedRecord { record, source: None })
+                    .map_err(|_| AleoError::invalid_input("Decryption failed - view key did not match record")),
+            ),
+            Ok(_) => None,
+            Err(_) => Some(Err(AleoError::parse(format!("{ciphertext} is not a valid record ciphertext")))),
+        })
+        .collect()
+}
+
+// Decrypt the records owned by a view key in the outputs of transitions
+fn scan_transitions(
+    view_key: &ViewKeyNative,
+    transitions: &[TransitionNative],
+    programs: &IndexMap<ProgramIDNative, ProgramNative>,
+) -> Result<Vec<OwnedRecord>, AleoError> {
+    transitions
+        .par_iter()
+        .flat_map_iter(|transition| {
+            transition.outputs().iter().enumerate().filter_map(move |(output_index, output)| match output {
+                Output::Record(commitment, _, Some(record)) if record.is_owner(view_key) => Some(
+                    record
+                        .decrypt(view_key)
+                        .map(|record| OwnedRecord {
+                            record,
+                            source: Some(RecordSource {
+                                commitment: *commitment,
+                                program: transition.program_id().to_string(),
+                                record_name: record_name(programs, transition, output_index),
+                                transition_id: transition.id().to_string(),
+                                function: transition.function_name().to_string(),
+                                output_index,
+                            }),
+                        })
+                        .map_err(|_| AleoError::invalid_input("Decryption failed - view key did not match record")),
+                ),
+                _ => None,
+            })
+        })
+        .collect()
+}
+
+// Resolve the name of a record output from the declaration of the function that produced it
+fn record_name(
+    programs: &IndexMap<ProgramIDNative, ProgramNative>,
+    transition: &TransitionNative,
+    output_index: usize,
+) -> Option<String> {
+    let function = programs.get(transition.program_id())?.get_function(transition.function_name()).ok()?;
+    match function.outputs().iter().nth(output_index)?.value_type() {
+        ValueType::Record(record_name) => Some(record_name.to_string()),
+        _ => None,
+    }
+}
+
+// Collect the strings of a javascript array
+fn strings_from_array(array: &Array, kind: &str) -> Result<Vec<String>, AleoError> {
+    array
+        .iter()
+        .map(|value| {
+            value.as_string().ok_or_else(|| AleoError::invalid_input(format!("Every {kind} must be a string")))
+        })
+        .collect()
+}
+
+// Parse the programs used to resolve record names, including credits.aleo
+fn programs_from_object(programs: Option<Object>) -> Result<IndexMap<ProgramIDNative, ProgramNative>, AleoError> {
+    let credits = ProgramNative::credits().map_err(AleoError::parse)?;
+    let mut parsed = IndexMap::from([(*credits.id(), credits)]);
+    if let Some(programs) = programs {
+        for source in Object::values(&programs).iter() {
+            let source =
+                source.as_string().ok_or_else(|| AleoError::invalid_input("Every program must be a string"))?;
+            let program = ProgramNative::from_str(&source).map_err(AleoError::parse)?;
+            parsed.insert(*program.id(), program);
+        }
+    }
+    Ok(parsed)
+}
+
+// Create the javascript objects describing owned records
+fn owned_records_to_array(records: Vec<OwnedRecord>) -> Result<Array, AleoError> {
+    let array = Array::new_with_length(records.len() as u32);
+    for (index, owned) in records.into_iter().enumerate() {
+        let object = Object::new();
+        Reflect::set(&object, &"record".into(), &RecordPlaintext::from(owned.record).into())
+            .map_err(|_| AleoError::serialization("Failed to set property"))?;
+        if let Some(source) = owned.source {
+            Reflect::set(&object, &"commitment".into(), &source.commitment.to_string().into())
+                .map_err(|_| AleoError::serialization("Failed to set property"))?;
+            Reflect::set(&object, &"program".into(), &source.program.into())
+                .map_err(|_| AleoError::serialization("Failed to set property"))?;
+            Reflect::set(&object, &"recordName".into(), &source.record_name.into())
+                .map_err(|_| AleoError::serialization("Failed to set property"))?;
+            Reflect::set(&object, &"transitionId".into(), &source.transition_id.into())
+                .map_err(|_| AleoError::serialization("Failed to set property"))?;
+            Reflect::set(&object, &"function".into(), &source.function.into())
+                .map_err(|_| AleoError::serialization("Failed to set property"))?;
+            Reflect::set(&object, &"outputIndex".into(), &(source.output_index as u32).into())
+                .map_err(|_| AleoError::serialization("Failed to set property"))?;
+        }
+        array.set(index as u32, object.into());
+    }
+    Ok(array)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        types::native::{CurrentAleo, IdentifierNative, PrivateKeyNative, ProcessNative},
+        Credits,
+    };
+
+    use snarkvm_console::prelude::TestRng;
+    use wasm_bindgen_test::*;
+
+    const OWNER_CIPHERTEXT: &str = "record1qyqsqpe2szk2wwwq56akkwx586hkndl3r8vzdwve32lm7elvphh37rsyqyxx66trwfhkxun9v35hguerqqpqzqrtjzeu6vah9x2me2exkgege824sd8x2379scspmrmtvczs0d93qttl7y92ga0k0rsexu409hu3vlehe3yxjhmey3frh2z5pxm5cmxsv4un97q";
+    const OWNER_VIEW_KEY: &str = "AViewKey1ccEt8A2Ryva5rxnKcAbn7wgTaTsb79tzkKHFpeKsm9NX";
+    const NON_OWNER_VIEW_KEY: &str = "AViewKey1e2WyreaH5H4RBcioLL2GnxvHk5Ud46EtwycnhTdXLmXp";
+
+    const MINT_PROGRAM: &str = r"program mint_test.aleo;
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+
+function mint:
+    input r0 as address.private;
+    input r1 as u64.private;
+    cast r0 r1 into r2 as token.record;
+    output r2 as token.record;
+";
+
+    #[wasm_bindgen_test]
+    fn test_scan_record_ciphertexts() {
+        let owner = ViewKeyNative::from_str(OWNER_VIEW_KEY).unwrap();
+        let non_owner = ViewKeyNative::from_str(NON_OWNER_VIEW_KEY).unwrap();
+        let ciphertexts = vec![OWNER_CIPHERTEXT.to_string(); 3];
+
+        let records = scan_record_ciphertexts(&owner, &ciphertexts).unwrap();
+        assert_eq!(records.len(), 3);
+        assert!(records.iter().all(|owned| owned.record.microcredits().is_ok() && owned.source.is_none()));
+        assert!(scan_record_ciphertexts(&non_owner, &ciphertexts).unwrap().is_empty());
+        assert!(scan_record_ciphertexts(&owner, &["record1invalid".to_string()]).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_scan_transitions() {
+        let mut rng = TestRng::default();
+        let signer = PrivateKeyNative::new(&mut rng).unwrap();
+        let recipient = ViewKeyNative::try_from(PrivateKeyNative::new(&mut rng).unwrap()).unwrap();
+        let recipient_address = recipient.to_address().to_string();
+
+        let program = ProgramNative::from_str(MINT_PROGRAM).unwrap();
+        let mut process = ProcessNative::load_web().unwrap();
+        process.add_program(&program).unwrap();
+        let authorization = process
+            .authorize::<CurrentAleo, _>(
+                &signer,
+                "mint_test.aleo",
+                "mint",
+                [recipient_address.as_str(), "100u64"].into_iter(),
+                &mut rng,
+            )
+            .unwrap();
+        let transitions = authorization.transitions().values().cloned().collect::<Vec<_>>();
+        let commitment = match &transitions[0].outputs()[0] {
+            Output::Record(commitment, ..) => *commitment,
+            _ => panic!("Expected a record output"),
+        };
+
+        // Assert that record names are resolved when the program is provided
+        let programs = IndexMap::from([(*program.id(), program.clone())]);
+        let records = scan_transitions(&recipient, &transitions, &programs).unwrap();
+        assert_eq!(records.len(), 1);
+        let source = records[0].source.as_ref().unwrap();
+        assert_eq!(source.commitment, commitment);
+        assert_eq!(source.program, "mint_test.aleo");
+        assert_eq!(source.record_name.as_deref(), Some("token"));
+        assert_eq!(source.function, "mint");
+        assert_eq!(source.output_index, 0);
+        let recomputed = records[0].record.to_commitment(program.id(), &IdentifierNative::from_str("token").unwrap());
+        assert_eq!(recomputed.unwrap(), commitment);
+
+        // Assert that records are still found when the record name cannot be resolved
+        let records = scan_transitions(&recipient, &transitions, &IndexMap::new()).unwrap();
+        assert_eq!(records.len(), 1);
+        assert!(records[0].source.as_ref().unwrap().record_name.is_none());
+
+        let signer_view_key = ViewKeyNative::try_from(signer).unwrap();
+        assert!(scan_transitions(&signer_view_key, &transitions, &programs).unwrap().is_empty());
+    }
+}
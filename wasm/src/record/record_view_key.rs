@@ -0,0 +1,171 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Aleo SDK library.
+
+// The Aleo SDK library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Aleo SDK library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{RecordCiphertext, RecordPlaintext};
+use crate::{account::ViewKey, AleoError};
+
+use crate::types::native::{FieldNative, GroupNative};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use wasm_bindgen::prelude::*;
+
+/// Version of the record view key format
+const RECORD_VIEW_KEY_VERSION: u8 = 1;
+
+/// View key of a single record. The record view key `(nonce * view_key).x` is the symmetric key the
+/// record data is encrypted with, so it can be shared with an auditor to reveal one record without
+/// exposing the account view key and the rest of its history. It is bound to the nonce of its
+/// record and refuses to decrypt any other record
+#[wasm_bindgen]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecordViewKey {
+    nonce: GroupNative,
+    key: FieldNative,
+}
+
+/// Versioned envelope used to serialize record view keys
+#[derive(Deserialize, Serialize)]
+struct RecordViewKeyEnvelope {
+    version: u8,
+    nonce: String,
+    key: String,
+}
+
+#[wasm_bindgen]
+impl RecordViewKey {
+    /// Derive the view key of a record from the view key of its owner
+    ///
+    /// @param {ViewKey} view_key View key of the owner of the record
+    /// @param {RecordCiphertext} record Record ciphertext
+    /// @returns {RecordViewKey} Record view key
+    #[wasm_bindgen(js_name = fromViewKey)]
+    pub fn from_view_key(view_key: &ViewKey, record: &RecordCiphertext) -> Result<RecordViewKey, AleoError> {
+        if !record.is_owner(view_key) {
+            return Err(AleoError::invalid_input("The view key provided does not own the record"));
+        }
+        let nonce = *(**record).nonce();
+        Ok(Self { nonce, key: (nonce * ***view_key).to_x_coordinate() })
+    }
+
+    /// Decrypt the record the record view key was derived for
+    ///
+    /// @param {RecordCiphertext} record Record ciphertext
+    /// @returns {RecordPlaintext} Record plaintext
+    pub fn decrypt(&self, record: &RecordCiphertext) -> Result<RecordPlaintext, AleoError> {
+        if *(**record).nonce() != self.nonce {
+            return Err(AleoError::invalid_input("The record view key does not correspond to the record"));
+        }
+        let plaintext = record.decrypt_symmetric_unchecked(&self.key).map_err(|_| {
+            AleoError::invalid_input("Decryption failed - the record view key did not match the record")
+        })?;
+        Ok(RecordPlaintext::from(plaintext))
+    }
+
+    /// Returns the nonce of the record the record view key was derived for
+    ///
+    /// @returns {string} Nonce of the record
+    pub fn nonce(&self) -> String {
+        self.nonce.to_string()
+    }
+
+    /// Returns the JSON string representation of the record view key
+    ///
+    /// @returns {string} Record view key string
+    #[allow(clippy::inherent_to_string)]
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_string(&self) -> String {
+        serde_json::to_string(&RecordViewKeyEnvelope {
+            version: RECORD_VIEW_KEY_VERSION,
+            nonce: self.nonce.to_string(),
+            key: self.key.to_string(),
+        })
+        .unwrap()
+    }
+
+    /// Creates a RecordViewKey from its JSON string representation
+    ///
+    /// @param {string} record_view_key Record view key string
+    /// @returns {RecordViewKey} Record view key
+    #[wasm_bindgen(js_name = fromString)]
+    pub fn from_string(record_view_key: &str) -> Result<RecordViewKey, AleoError> {
+        let envelope: RecordViewKeyEnvelope =
+            serde_json::from_str(record_view_key).map_err(|_| AleoError::parse("Invalid record view key"))?;
+        if envelope.version != RECORD_VIEW_KEY_VERSION {
+            return Err(AleoError::parse(format!("Unsupported record view key version {}", envelope.version)));
+        }
+        let nonce = GroupNative::from_str(&envelope.nonce).map_err(|_| AleoError::parse("Invalid record nonce"))?;
+        let key = FieldNative::from_str(&envelope.key).map_err(|_| AleoError::parse("Invalid record view key"))?;
+        Ok(Self { nonce, key })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{
+        native::{ScalarNative, Uniform},
+        Scalar,
+    };
+
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    const OWNER_PLAINTEXT: &str = r"{
+  owner: aleo1j7qxyunfldj2lp8hsvy7mw5k8zaqgjfyr72x2gh3x4ewgae8v5gscf5jh3.private,
+  microcredits: 1500000000000000u64.private,
+  _nonce: 3077450429259593211617823051143573281856129402760267155982965992208217472983group.public
+}";
+    const OWNER_CIPHERTEXT: &str = "record1qyqsqpe2szk2wwwq56akkwx586hkndl3r8vzdwve32lm7elvphh37rsyqyxx66trwfhkxun9v35hguerqqpqzqrtjzeu6vah9x2me2exkgege824sd8x2379scspmrmtvczs0d93qttl7y92ga0k0rsexu409hu3vlehe3yxjhmey3frh2z5pxm5cmxsv4un97q";
+    const OWNER_VIEW_KEY: &str = "AViewKey1ccEt8A2Ryva5rxnKcAbn7wgTaTsb79tzkKHFpeKsm9NX";
+    const NON_OWNER_VIEW_KEY: &str = "AViewKey1e2WyreaH5H4RBcioLL2GnxvHk5Ud46EtwycnhTdXLmXp";
+
+    #[wasm_bindgen_test]
+    fn test_record_view_key_decrypts_one_record() {
+        let view_key = ViewKey::from_string(OWNER_VIEW_KEY).unwrap();
+        let record = RecordCiphertext::from_string(OWNER_CIPHERTEXT).unwrap();
+        let record_view_key = RecordViewKey::from_view_key(&view_key, &record).unwrap();
+        assert_eq!(record_view_key.nonce(), record.nonce());
+        assert_eq!(record_view_key.decrypt(&record).unwrap().to_string(), OWNER_PLAINTEXT);
+
+        // Assert another record of the same owner cannot be decrypted with the record view key
+        let randomizer: ScalarNative = Uniform::rand(&mut rand::thread_rng());
+        let randomizer = Scalar::from(randomizer);
+        let other_record = RecordPlaintext::from_string(OWNER_PLAINTEXT)
+            .unwrap()
+            .with_randomizer(&randomizer)
+            .unwrap()
+            .encrypt(&randomizer)
+            .unwrap();
+        assert!(record_view_key.decrypt(&other_record).is_err());
+        assert!(view_key.decrypt(&other_record.to_string()).is_ok());
+
+        // Assert record view keys can only be derived by the owner of the record
+        assert!(RecordViewKey::from_view_key(&ViewKey::from_string(NON_OWNER_VIEW_KEY).unwrap(), &record).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_record_view_key_to_and_from_string() {
+        let view_key = ViewKey::from_string(OWNER_VIEW_KEY).unwrap();
+        let record = RecordCiphertext::from_string(OWNER_CIPHERTEXT).unwrap();
+        let record_view_key = RecordViewKey::from_view_key(&view_key, &record).unwrap();
+        let recovered = RecordViewKey::from_string(&record_view_key.to_string()).unwrap();
+        assert_eq!(record_view_key, recovered);
+        assert_eq!(recovered.decrypt(&record).unwrap().to_string(), OWNER_PLAINTEXT);
+
+        assert!(RecordViewKey::from_string("garbage").is_err());
+        let tampered = record_view_key.to_string().replace("\"version\":1", "\"version\":2");
+        assert!(RecordViewKey::from_string(&tampered).is_err());
+    }
+}
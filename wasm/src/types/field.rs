@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Aleo SDK library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::types::native::FieldNative;
+use crate::{types::native::FieldNative, AleoError};
 
 use wasm_bindgen::prelude::wasm_bindgen;
 
@@ -33,8 +33,8 @@ impl Field {
     }
 
     #[wasm_bindgen(js_name = "fromString")]
-    pub fn from_string(field: &str) -> Result<Field, String> {
-        Ok(Self(FieldNative::from_str(field).map_err(|e| e.to_string())?))
+    pub fn from_string(field: &str) -> Result<Field, AleoError> {
+        Ok(Self(FieldNative::from_str(field).map_err(AleoError::parse)?))
     }
 }
 
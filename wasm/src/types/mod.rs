@@ -17,6 +17,12 @@
 pub mod field;
 pub use field::*;
 
+pub mod group;
+pub use group::*;
+
 mod networks;
 
+pub mod scalar;
+pub use scalar::*;
+
 pub(crate) mod native;
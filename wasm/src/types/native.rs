@@ -17,29 +17,36 @@
 pub use super::networks::*;
 
 pub use snarkvm_console::{
-    account::{Address, PrivateKey, Signature, ViewKey},
+    account::{Address, ComputeKey, GraphKey, PrivateKey, Signature, ViewKey},
     network::Network,
     program::{
         Ciphertext,
         Entry,
         EntryType,
+        FinalizeType,
         Identifier,
         Literal,
+        LiteralType,
+        Locator,
+        Owner,
         Plaintext,
         PlaintextType,
         ProgramID,
         ProgramOwner,
         Record,
+        RegisterType,
         Response,
+        Value,
         ValueType,
     },
-    types::Field,
+    types::{Field, Group, Scalar, U16},
 };
-pub use snarkvm_ledger_block::{Execution, Transaction};
+pub use snarkvm_ledger_block::{Block, Execution, Input, Output, Transaction, Transition};
 pub use snarkvm_ledger_query::Query;
 pub use snarkvm_ledger_store::helpers::memory::BlockMemory;
 pub use snarkvm_synthesizer::{
     process::{cost_in_microcredits, deployment_cost},
+    program::{CallOperator, Instruction, StackMatches},
     snark::{ProvingKey, VerifyingKey},
     Process,
     Program,
@@ -47,17 +54,21 @@ pub use snarkvm_synthesizer::{
 pub use snarkvm_wasm::{
     console::network::Environment,
     fields::PrimeField,
-    utilities::{FromBytes, ToBytes, Uniform},
+    utilities::{FromBytes, ToBits, ToBytes, Uniform},
 };
 
 // Account types
 pub type AddressNative = Address<CurrentNetwork>;
+pub type ComputeKeyNative = ComputeKey<CurrentNetwork>;
+pub type GraphKeyNative = GraphKey<CurrentNetwork>;
 pub type PrivateKeyNative = PrivateKey<CurrentNetwork>;
 pub type SignatureNative = Signature<CurrentNetwork>;
 pub type ViewKeyNative = ViewKey<CurrentNetwork>;
 
 // Algebraic types
 pub type FieldNative = Field<CurrentNetwork>;
+pub type GroupNative = Group<CurrentNetwork>;
+pub type ScalarNative = Scalar<CurrentNetwork>;
 
 // Record types
 pub type CiphertextNative = Ciphertext<CurrentNetwork>;
@@ -67,9 +78,11 @@ pub type RecordPlaintextNative = Record<CurrentNetwork, PlaintextNative>;
 
 // Program types
 type CurrentBlockMemory = BlockMemory<CurrentNetwork>;
+pub type BlockNative = Block<CurrentNetwork>;
 pub type ExecutionNative = Execution<CurrentNetwork>;
 pub type IdentifierNative = Identifier<CurrentNetwork>;
 pub type LiteralNative = Literal<CurrentNetwork>;
+pub type LocatorNative = Locator<CurrentNetwork>;
 pub type ProcessNative = Process<CurrentNetwork>;
 pub type ProgramIDNative = ProgramID<CurrentNetwork>;
 pub type ProgramNative = Program<CurrentNetwork>;
@@ -78,4 +91,6 @@ pub type ProvingKeyNative = ProvingKey<CurrentNetwork>;
 pub type QueryNative = Query<CurrentNetwork, CurrentBlockMemory>;
 pub type ResponseNative = Response<CurrentNetwork>;
 pub type TransactionNative = Transaction<CurrentNetwork>;
+pub type TransitionNative = Transition<CurrentNetwork>;
+pub type ValueNative = Value<CurrentNetwork>;
 pub type VerifyingKeyNative = VerifyingKey<CurrentNetwork>;